@@ -8,6 +8,7 @@ use cairo_lang_filesystem::db::{
     CrateIdentifier, CrateSettings, DependencySettings, Edition, ExperimentalFeaturesConfig,
 };
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lint_core::config::{LintConfig, LintLevel, LintOverride};
 use scarb_metadata::{Cfg as ScarbCfg, CompilationUnitMetadata, PackageId, PackageMetadata};
 use semver::Version;
 use smol_str::ToSmolStr;
@@ -55,6 +56,72 @@ pub fn to_cairo_edition(edition: &str) -> Result<Edition> {
     }
 }
 
+/// Builds the [`LintConfig`] of a package from the `guard_functions`, the
+/// `zero_address_guard_functions` and the `overrides` of its `[tool.cairo-lint]` section:
+/// ```toml
+/// [tool.cairo-lint]
+/// guard_functions = ["assert_only_admin"]
+/// zero_address_guard_functions = ["assert_valid_address"]
+///
+/// [[tool.cairo-lint.overrides]]
+/// paths = ["src/tests/**"]
+/// modules = ["my_package::mocks"]
+/// allow = ["panic", "manual_expect"]
+/// deny = ["eq_op"]
+/// ```
+pub fn to_lint_config(package: &PackageMetadata) -> Result<LintConfig> {
+    let mut lint_config = LintConfig {
+        root: package.root.clone().into(),
+        overrides: Vec::new(),
+        guard_functions: Vec::new(),
+        zero_address_guard_functions: Vec::new(),
+        unused_allow: false,
+    };
+    let Some(config) = package.tool_metadata("cairo-lint") else {
+        return Ok(lint_config);
+    };
+    lint_config.guard_functions = config["guard_functions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|value| value.as_str().map(ToString::to_string))
+        .collect();
+    lint_config.zero_address_guard_functions = config["zero_address_guard_functions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|value| value.as_str().map(ToString::to_string))
+        .collect();
+    lint_config.unused_allow = config["unused_allow"].as_bool().unwrap_or_default();
+    let Some(overrides) = config["overrides"].as_array() else {
+        return Ok(lint_config);
+    };
+    for lint_override in overrides {
+        let strings = |key: &str| -> Vec<String> {
+            lint_override[key]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|value| value.as_str().map(ToString::to_string))
+                .collect()
+        };
+        let levels = [
+            ("allow", LintLevel::Allow),
+            ("warn", LintLevel::Warn),
+            ("deny", LintLevel::Deny),
+        ]
+        .into_iter()
+        .flat_map(|(key, level)| strings(key).into_iter().map(move |lint| (lint, level)))
+        .collect();
+        lint_config.overrides.push(LintOverride::new(
+            &strings("paths"),
+            strings("modules"),
+            levels,
+        )?);
+    }
+    Ok(lint_config)
+}
+
 /// Gets a bunch of informations related to the project from several objects.
 ///
 /// Mostly a copy pasta of
@@ -1,31 +1,27 @@
 pub mod helpers;
 
-use std::cmp::Reverse;
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use annotate_snippets::Renderer;
 use anyhow::{anyhow, Result};
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::project::update_crate_roots_from_project_config;
 use cairo_lang_defs::db::DefsGroup;
-use cairo_lang_diagnostics::{DiagnosticEntry, Maybe};
+use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::db::{
     init_dev_corelib, CrateIdentifier, FilesGroup, CORELIB_CRATE_NAME,
 };
-use cairo_lang_filesystem::ids::{CrateLongId, FileId};
+use cairo_lang_filesystem::ids::CrateLongId;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_starknet::starknet_plugin_suite;
-use cairo_lang_syntax::node::SyntaxNode;
 use cairo_lang_test_plugin::test_plugin_suite;
 use cairo_lang_utils::{Upcast, UpcastMut};
-use cairo_lint_core::diagnostics::format_diagnostic;
-use cairo_lint_core::fix::{
-    apply_import_fixes, collect_unused_imports, fix_semantic_diagnostic, Fix, ImportFix,
-};
+use cairo_lint_core::diagnostics::{format_diagnostic, format_diagnostic_with_fix, LintDetails};
+use cairo_lint_core::fix::{apply_fixes, collect_fixes};
 use cairo_lint_core::plugin::{
-    cairo_lint_plugin_suite, diagnostic_kind_from_message, CairoLintKind,
+    cairo_lint_plugin_suite_with_details, diagnostic_kind_from_message, lint_name_from_message,
+    CairoLintKind,
 };
 use clap::Parser;
 use helpers::*;
@@ -87,6 +83,8 @@ fn main_inner(ui: &Ui, args: Args) -> Result<()> {
 
     // Let's lint everything requested
     for package in matched {
+        // Get the lint levels overridden for some paths or modules
+        let lint_config = to_lint_config(&package)?;
         // Get the current package metadata
         let compilation_units = if args.test {
             let tests_targets = find_testable_targets(&package);
@@ -108,17 +106,25 @@ fn main_inner(ui: &Ui, args: Args) -> Result<()> {
         for compilation_unit in compilation_units {
             // Print that we're checking this package.
             ui.print(Status::new("Checking", &compilation_unit.target.name));
+            // The details of the diagnostics, collected while linting.
+            let details = LintDetails::default();
             // Create our db
             let mut db = if args.test {
                 RootDatabase::builder()
                     .with_plugin_suite(test_plugin_suite())
-                    .with_plugin_suite(cairo_lint_plugin_suite())
+                    .with_plugin_suite(cairo_lint_plugin_suite_with_details(
+                        lint_config.clone(),
+                        details.clone(),
+                    ))
                     .with_plugin_suite(starknet_plugin_suite())
                     .with_cfg(to_cairo_cfg(&compilation_unit.cfg))
                     .build()?
             } else {
                 RootDatabase::builder()
-                    .with_plugin_suite(cairo_lint_plugin_suite())
+                    .with_plugin_suite(cairo_lint_plugin_suite_with_details(
+                        lint_config.clone(),
+                        details.clone(),
+                    ))
                     .with_plugin_suite(starknet_plugin_suite())
                     .with_cfg(to_cairo_cfg(&compilation_unit.cfg))
                     .build()?
@@ -168,7 +174,7 @@ fn main_inner(ui: &Ui, args: Args) -> Result<()> {
 
             for module_id in &*db.crate_modules(crate_id) {
                 if let Maybe::Ok(module_diags) = db.module_semantic_diagnostics(*module_id) {
-                    diags.push(module_diags);
+                    diags.push((*module_id, module_diags));
                 }
             }
 
@@ -176,94 +182,61 @@ fn main_inner(ui: &Ui, args: Args) -> Result<()> {
 
             let diagnostics = diags
                 .iter()
-                .flat_map(|diags| {
+                .flat_map(|(module_id, diags)| {
+                    let module_path = module_id.full_path(db.upcast());
                     let all_diags = diags.get_all();
                     all_diags
                         .iter()
                         .filter(|diag| {
                             if let SemanticDiagnosticKind::PluginDiagnostic(diag) = &diag.kind {
-                                (matches!(
-                                    diagnostic_kind_from_message(&diag.message),
-                                    CairoLintKind::Panic
-                                ) && should_lint_panics)
-                                    || !matches!(
-                                        diagnostic_kind_from_message(&diag.message),
-                                        CairoLintKind::Panic
-                                    )
+                                // The lints with an overridden level are always reported, the
+                                // allowed ones have already been removed by the plugin.
+                                let is_overridden = lint_name_from_message(&diag.message)
+                                    .is_some_and(|lint_name| {
+                                        let file_path = diag
+                                            .stable_ptr
+                                            .file_id(db.upcast())
+                                            .full_path(db.upcast());
+                                        lint_config
+                                            .lint_level(
+                                                lint_name,
+                                                Path::new(&file_path),
+                                                &module_path,
+                                            )
+                                            .is_some()
+                                    });
+                                if is_overridden {
+                                    return true;
+                                }
+                                match diagnostic_kind_from_message(&diag.message) {
+                                    CairoLintKind::Panic => should_lint_panics,
+                                    _ => true,
+                                }
                             } else {
                                 true
                             }
                         })
-                        .for_each(|diag| ui.print(format_diagnostic(diag, &db, &renderer)));
+                        .for_each(|diag| {
+                            // When fixing, the suggestions are applied directly so there's no need
+                            // to display them.
+                            if args.fix {
+                                ui.print(format_diagnostic(diag, &db, &renderer, &details))
+                            } else {
+                                ui.print(format_diagnostic_with_fix(diag, &db, &renderer, &details))
+                            }
+                        });
                     all_diags
                 })
                 .collect::<Vec<_>>();
 
             if args.fix {
-                // Handling unused imports separately as we need to run pre-analysis on the diagnostics.
-                // to handle complex cases.
-                let unused_imports: HashMap<FileId, HashMap<SyntaxNode, ImportFix>> =
-                    collect_unused_imports(&db, &diagnostics);
-                let mut fixes = HashMap::new();
-                unused_imports.keys().for_each(|file_id| {
-                    let file_fixes: Vec<Fix> =
-                        apply_import_fixes(&db, unused_imports.get(file_id).unwrap());
-                    fixes.insert(*file_id, file_fixes);
-                });
-
-                let diags_without_imports = diagnostics
-                    .iter()
-                    .filter(|diag| !matches!(diag.kind, SemanticDiagnosticKind::UnusedImport(_)))
-                    .collect::<Vec<_>>();
-
-                for diag in diags_without_imports {
-                    if let Some((fix_node, fix)) = fix_semantic_diagnostic(&db, diag) {
-                        let location = diag.location(db.upcast());
-                        fixes
-                            .entry(location.file_id)
-                            .or_insert_with(Vec::new)
-                            .push(Fix {
-                                span: fix_node.span(db.upcast()),
-                                suggestion: fix,
-                            });
-                    }
-                }
-                for (file_id, mut fixes) in fixes.into_iter() {
+                for (file_id, fixes) in collect_fixes(&db, &diagnostics) {
                     ui.print(Status::new("Fixing", &file_id.file_name(db.upcast())));
-                    fixes.sort_by_key(|fix| Reverse(fix.span.start));
-                    let mut fixable_diagnostics = Vec::with_capacity(fixes.len());
-                    if fixes.len() <= 1 {
-                        fixable_diagnostics = fixes;
-                    } else {
-                        // Check if we have nested diagnostics. If so it's a nightmare to fix hence just ignore it
-                        for i in 0..fixes.len() - 1 {
-                            let first = fixes[i].span;
-                            let second = fixes[i + 1].span;
-                            if first.start >= second.end {
-                                fixable_diagnostics.push(fixes[i].clone());
-                                if i == fixes.len() - 1 {
-                                    fixable_diagnostics.push(fixes[i + 1].clone());
-                                }
-                            }
-                        }
-                    }
-                    // Get all the files that need to be fixed
-                    let mut files: HashMap<FileId, String> = HashMap::default();
-                    files.insert(
-                        file_id,
-                        db.file_content(file_id)
-                            .ok_or(anyhow!("{} not found", file_id.file_name(db.upcast())))?
-                            .to_string(),
-                    );
-                    // Fix the files
-                    for fix in fixable_diagnostics {
-                        // Can't fail we just set the file value.
-                        files.entry(file_id).and_modify(|file| {
-                            file.replace_range(fix.span.to_str_range(), &fix.suggestion)
-                        });
-                    }
+                    let content = db
+                        .file_content(file_id)
+                        .ok_or(anyhow!("{} not found", file_id.file_name(db.upcast())))?;
                     // Dump them in place
-                    std::fs::write(file_id.full_path(db.upcast()), files.get(&file_id).unwrap())?
+                    std::fs::write(file_id.full_path(db.upcast()), apply_fixes(&content, fixes))?
                 }
             }
         }
@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+
+use cairo_lang_defs::ids::FunctionWithBodyId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{
+    Arenas, Condition, Expr, ExprFunctionCallArg, ExprId, ExprIf, ExprVarMemberPath, ExprWhile,
+    PatternId, Statement, VarId,
+};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use super::execution_order::ExecutionOrder;
+
+/// How a definition gives a value to a variable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DefinitionKind {
+    /// A parameter of the function, whose value is given by the caller.
+    Param,
+    /// A `let` statement binding the variable to the value of the expression, or to a part of it
+    /// when the pattern destructures it.
+    Let(ExprId),
+    /// A pattern matched against the value of the expression, in a `match` arm, an `if let`, a
+    /// `while let` or a `for` loop (whose expression is the iterated one).
+    Pattern(ExprId),
+    /// An assignment of the value of the expression to the variable, or to one of its members
+    /// (e.g. `x.a = value`).
+    Assignment(ExprId),
+    /// A function call taking the variable by reference, e.g. `array.append(value)`, the
+    /// expression being the call.
+    Reference(ExprId),
+}
+
+/// A point of the function where a variable gets a value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Definition {
+    pub var: VarId,
+    pub kind: DefinitionKind,
+    /// The node after whose evaluation the variable has the value, e.g. the `let` statement.
+    pub stable_ptr: SyntaxStablePtrId,
+    /// Whether the whole variable is given a value, as opposed to one of its members.
+    pub is_complete: bool,
+}
+
+/// A point of the function where the value of a variable is used.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Use {
+    pub var: VarId,
+    /// The expression reading the variable, or the function call taking it by reference.
+    pub expr: ExprId,
+    pub stable_ptr: SyntaxStablePtrId,
+}
+
+/// The index of a definition in [`DefUse::definitions`].
+pub type DefinitionId = usize;
+
+/// The definitions and the uses of the variables of a function body, along with the definitions
+/// reaching each use.
+///
+/// Like the other analyses, the reaching definitions rely on the [`ExecutionOrder`] of the nodes:
+/// a definition reaches a use if it may be evaluated before it and isn't always replaced by a
+/// complete definition of the variable evaluated in between.
+pub struct DefUse {
+    definitions: Vec<Definition>,
+    uses: HashMap<VarId, Vec<Use>>,
+    execution_order: ExecutionOrder,
+}
+
+impl DefUse {
+    pub fn new(db: &dyn SemanticGroup, func_id: FunctionWithBodyId, arenas: &Arenas) -> Self {
+        let mut definitions = Vec::new();
+        if let Ok(signature) = db.function_with_body_signature(func_id) {
+            definitions.extend(signature.params.into_iter().map(|param| Definition {
+                var: VarId::Param(param.id),
+                kind: DefinitionKind::Param,
+                stable_ptr: param.stable_ptr.untyped(),
+                is_complete: true,
+            }));
+        }
+        let pattern_definitions = |pattern: PatternId, kind: DefinitionKind| {
+            arenas.patterns[pattern]
+                .variables(&arenas.patterns)
+                .into_iter()
+                .map(move |variable| Definition {
+                    var: VarId::Local(variable.var.id),
+                    kind,
+                    stable_ptr: variable.stable_ptr.untyped(),
+                    is_complete: true,
+                })
+        };
+        for (_statement_id, statement) in &arenas.statements {
+            if let Statement::Let(statement_let) = statement {
+                definitions.extend(
+                    pattern_definitions(
+                        statement_let.pattern,
+                        DefinitionKind::Let(statement_let.expr),
+                    )
+                    // The variables are bound once the expression is evaluated.
+                    .map(|definition| Definition {
+                        stable_ptr: statement_let.stable_ptr.untyped(),
+                        ..definition
+                    }),
+                );
+            }
+        }
+        let mut uses: HashMap<VarId, Vec<Use>> = HashMap::new();
+        for (expr_id, expr) in &arenas.exprs {
+            let stable_ptr = expr.stable_ptr().untyped();
+            match expr {
+                Expr::Var(expr_var) => uses.entry(expr_var.var).or_default().push(Use {
+                    var: expr_var.var,
+                    expr: expr_id,
+                    stable_ptr,
+                }),
+                Expr::Assignment(assignment) => definitions.push(Definition {
+                    var: assignment.ref_arg.base_var(),
+                    kind: DefinitionKind::Assignment(assignment.rhs),
+                    stable_ptr,
+                    is_complete: matches!(assignment.ref_arg, ExprVarMemberPath::Var(_)),
+                }),
+                Expr::FunctionCall(expr_func) => {
+                    for arg in &expr_func.args {
+                        if let ExprFunctionCallArg::Reference(ref_arg) = arg {
+                            let var = ref_arg.base_var();
+                            uses.entry(var).or_default().push(Use {
+                                var,
+                                expr: expr_id,
+                                stable_ptr,
+                            });
+                            definitions.push(Definition {
+                                var,
+                                kind: DefinitionKind::Reference(expr_id),
+                                stable_ptr,
+                                is_complete: false,
+                            });
+                        }
+                    }
+                }
+                Expr::Match(expr_match) => {
+                    for arm in &expr_match.arms {
+                        for pattern in &arm.patterns {
+                            definitions.extend(pattern_definitions(
+                                *pattern,
+                                DefinitionKind::Pattern(expr_match.matched_expr),
+                            ));
+                        }
+                    }
+                }
+                Expr::If(ExprIf {
+                    condition: Condition::Let(matched_expr, patterns),
+                    ..
+                })
+                | Expr::While(ExprWhile {
+                    condition: Condition::Let(matched_expr, patterns),
+                    ..
+                }) => {
+                    for pattern in patterns {
+                        definitions.extend(pattern_definitions(
+                            *pattern,
+                            DefinitionKind::Pattern(*matched_expr),
+                        ));
+                    }
+                }
+                Expr::For(expr_for) => definitions.extend(pattern_definitions(
+                    expr_for.pattern,
+                    DefinitionKind::Pattern(expr_for.expr_id),
+                )),
+                _ => {}
+            }
+        }
+        Self {
+            definitions,
+            uses,
+            execution_order: ExecutionOrder::new(db, arenas),
+        }
+    }
+
+    /// Gets the definitions of all the variables, indexed by [`DefinitionId`].
+    pub fn definitions(&self) -> &[Definition] {
+        &self.definitions
+    }
+
+    /// Gets the uses of the variable.
+    pub fn uses(&self, var: VarId) -> &[Use] {
+        self.uses.get(&var).map_or(&[], Vec::as_slice)
+    }
+
+    /// Gets the definitions of the variable that may give it its value when it's used by the node,
+    /// e.g. a variable expression or a function call taking the variable by reference.
+    pub fn reaching_definitions(
+        &self,
+        db: &dyn SemanticGroup,
+        var: VarId,
+        stable_ptr: SyntaxStablePtrId,
+    ) -> Vec<DefinitionId> {
+        let definitions = self
+            .definitions
+            .iter()
+            .enumerate()
+            .filter(|(_definition_id, definition)| definition.var == var)
+            .collect::<Vec<_>>();
+        definitions
+            .iter()
+            .filter(|(_definition_id, definition)| {
+                self.execution_order
+                    .may_run_before(db, definition.stable_ptr, stable_ptr)
+                    && !definitions.iter().any(|(_other_id, other)| {
+                        other != definition
+                            && other.is_complete
+                            && self.execution_order.always_runs_before(
+                                db,
+                                other.stable_ptr,
+                                stable_ptr,
+                            )
+                            && !self.execution_order.may_run_before(
+                                db,
+                                other.stable_ptr,
+                                definition.stable_ptr,
+                            )
+                    })
+            })
+            .map(|(definition_id, _definition)| *definition_id)
+            .collect()
+    }
+}
@@ -0,0 +1,98 @@
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use super::{contains, location, Location};
+
+/// The order in which the nodes of a function body may be evaluated, taking into account the
+/// branches of the `if` and `match` expressions and the loops.
+///
+/// Like [`super::liveness::Liveness`], the analysis relies on the source order of the nodes: a node
+/// is evaluated where its expression ends, so after its arguments. Nodes located in different
+/// files, e.g. in code generated by a macro, are considered as unordered.
+pub struct ExecutionOrder {
+    /// The branches of each `if` and `match` expression, only one of which is evaluated.
+    branchings: Vec<Vec<Location>>,
+    /// The loops, whose bodies may be evaluated several times.
+    loops: Vec<Location>,
+}
+
+impl ExecutionOrder {
+    pub fn new(db: &dyn SemanticGroup, arenas: &Arenas) -> Self {
+        let expr_location = |expr_id| location(db, arenas.exprs[expr_id].stable_ptr().untyped());
+        let mut branchings = Vec::new();
+        let mut loops = Vec::new();
+        for (_expr_id, expr) in &arenas.exprs {
+            match expr {
+                Expr::If(expr_if) => branchings.push(
+                    [Some(expr_if.if_block), expr_if.else_block]
+                        .into_iter()
+                        .flatten()
+                        .map(&expr_location)
+                        .collect(),
+                ),
+                Expr::Match(expr_match) => branchings.push(
+                    expr_match
+                        .arms
+                        .iter()
+                        .map(|arm| expr_location(arm.expression))
+                        .collect(),
+                ),
+                Expr::Loop(_) | Expr::While(_) | Expr::For(_) => {
+                    loops.push(location(db, expr.stable_ptr().untyped()))
+                }
+                _ => {}
+            }
+        }
+        Self { branchings, loops }
+    }
+
+    /// Checks if `first` may be evaluated before `second`, in the same iteration of the enclosing
+    /// loops or in a previous one.
+    pub fn may_run_before(
+        &self,
+        db: &dyn SemanticGroup,
+        first: SyntaxStablePtrId,
+        second: SyntaxStablePtrId,
+    ) -> bool {
+        let (first, second) = (location(db, first), location(db, second));
+        if first.0 != second.0 {
+            return false;
+        }
+        if self.loops.iter().any(|loop_location| {
+            contains(*loop_location, first) && contains(*loop_location, second)
+        }) {
+            return true;
+        }
+        first.1.end < second.1.end && !self.are_exclusive(first, second)
+    }
+
+    /// Checks if `first` is always evaluated before `second`, i.e. if it's evaluated before it and
+    /// isn't in a branch or a loop that `second` isn't in.
+    pub fn always_runs_before(
+        &self,
+        db: &dyn SemanticGroup,
+        first: SyntaxStablePtrId,
+        second: SyntaxStablePtrId,
+    ) -> bool {
+        let (first, second) = (location(db, first), location(db, second));
+        first.0 == second.0
+            && first.1.end < second.1.end
+            && self
+                .branchings
+                .iter()
+                .flatten()
+                .chain(&self.loops)
+                .all(|body| !contains(*body, first) || contains(*body, second))
+    }
+
+    /// Checks if the locations are in different branches of the same `if` or `match`.
+    fn are_exclusive(&self, first: Location, second: Location) -> bool {
+        self.branchings.iter().any(|branches| {
+            let first_branch = branches.iter().position(|branch| contains(*branch, first));
+            let second_branch = branches.iter().position(|branch| contains(*branch, second));
+            matches!((first_branch, second_branch), (Some(first), Some(second)) if first != second)
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::{HashMap, HashSet};
+
+use cairo_lang_defs::ids::FunctionWithBodyId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCallArg, Mutability, VarId};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use super::{contains, location, Location};
+
+/// The uses of the variables of a function body, to know if a variable is live at some point of
+/// the function, i.e. if its value may still be used afterwards.
+///
+/// The analysis relies on the source order of the expressions and is conservative: a variable is
+/// live after a node if it's used after it in the source, if it's used in a loop containing both
+/// the node and the declaration of the variable, or if it's a `ref` parameter, returned to the
+/// caller. Uses in the other branch of an `if` or a `match` are thus considered as later uses.
+pub struct Liveness {
+    uses: HashMap<VarId, Vec<Location>>,
+    loops: Vec<Location>,
+    ref_params: HashSet<VarId>,
+}
+
+impl Liveness {
+    pub fn new(db: &dyn SemanticGroup, func_id: FunctionWithBodyId, arenas: &Arenas) -> Self {
+        let ref_params = db
+            .function_with_body_signature(func_id)
+            .map(|signature| {
+                signature
+                    .params
+                    .into_iter()
+                    .filter(|param| param.mutability == Mutability::Reference)
+                    .map(|param| VarId::Param(param.id))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut uses: HashMap<VarId, Vec<Location>> = HashMap::new();
+        let mut loops = Vec::new();
+        for (_expr_id, expr) in &arenas.exprs {
+            let location = location(db, expr.stable_ptr().untyped());
+            match expr {
+                Expr::Var(expr_var) => uses.entry(expr_var.var).or_default().push(location),
+                Expr::Loop(_) | Expr::While(_) | Expr::For(_) => loops.push(location),
+                // The variables assigned or passed by reference are used once the expression is
+                // evaluated, e.g. in `array.append(array.len())` after `array.len()`.
+                Expr::Assignment(assignment) => uses
+                    .entry(assignment.ref_arg.base_var())
+                    .or_default()
+                    .push(location),
+                Expr::FunctionCall(expr_func) => {
+                    for arg in &expr_func.args {
+                        if let ExprFunctionCallArg::Reference(ref_arg) = arg {
+                            uses.entry(ref_arg.base_var()).or_default().push(location);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self {
+            uses,
+            loops,
+            ref_params,
+        }
+    }
+
+    /// Checks if the variable may be used after the evaluation of the node.
+    pub fn is_live_after(
+        &self,
+        db: &dyn SemanticGroup,
+        var: VarId,
+        stable_ptr: SyntaxStablePtrId,
+    ) -> bool {
+        if self.ref_params.contains(&var) {
+            return true;
+        }
+        let Some(uses) = self.uses.get(&var) else {
+            return false;
+        };
+        let node = location(db, stable_ptr);
+        let declaration = match var {
+            VarId::Local(local_var) => {
+                Some(location(db, local_var.untyped_stable_ptr(db.upcast())))
+            }
+            _ => None,
+        };
+        // The loops in which the variable keeps its value from an iteration to the next one.
+        let enclosing_loops = self
+            .loops
+            .iter()
+            .filter(|loop_location| {
+                contains(**loop_location, node)
+                    && !declaration
+                        .is_some_and(|declaration| contains(**loop_location, declaration))
+            })
+            .collect::<Vec<_>>();
+        uses.iter().any(|use_location| {
+            // The uses in another file, e.g. in code generated by a macro, can't be ordered.
+            use_location.0 != node.0
+                || use_location.1.end > node.1.end
+                || enclosing_loops
+                    .iter()
+                    .any(|loop_location| contains(**loop_location, *use_location))
+        })
+    }
+}
@@ -0,0 +1,40 @@
+//! Analyses of the function bodies shared by several lints.
+//!
+//! The analyses work on the semantic arenas of the functions (`db.function_body(..)`) rather than
+//! on their lowering: the lints run from [`cairo_lang_semantic::plugin::AnalyzerPlugin`], which is
+//! only given a [`cairo_lang_semantic::db::SemanticGroup`], so the `LoweringGroup` queries aren't
+//! reachable from them. For the same reason, the results can't be memoized as salsa queries, as
+//! the database is owned by the compiler or the language server, not by this crate. An analysis is
+//! thus computed once per function by the lints needing it, e.g. [`liveness::Liveness`] or
+//! [`def_use::DefUse`].
+
+use cairo_lang_filesystem::db::get_originating_location;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+
+pub mod def_use;
+pub mod execution_order;
+pub mod liveness;
+
+/// A location in the source code, as the file and the span of a node.
+pub(crate) type Location = (FileId, TextSpan);
+
+/// Gets the location of a node in the file it originates from, e.g. in the arguments of an inline
+/// macro for the code generated from them. The generated code that doesn't come from the user's
+/// code stays in the virtual file of the macro expansion.
+pub(crate) fn location(db: &dyn SemanticGroup, stable_ptr: SyntaxStablePtrId) -> Location {
+    get_originating_location(
+        db.upcast(),
+        stable_ptr.file_id(db.upcast()),
+        stable_ptr.lookup(db.upcast()).span(db.upcast()),
+        None,
+    )
+}
+
+/// Checks if a location contains another one. Locations in different files are never contained in
+/// each other, as their spans can't be compared.
+pub(crate) fn contains(outer: Location, inner: Location) -> bool {
+    outer.0 == inner.0 && outer.1.start <= inner.1.start && inner.1.end <= outer.1.end
+}
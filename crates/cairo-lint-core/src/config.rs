@@ -0,0 +1,114 @@
+use std::path::{Path, PathBuf};
+
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+/// Level of a lint, overriding the default behaviour of the lint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintLevel {
+    /// The lint isn't reported.
+    Allow,
+    /// The lint is reported as a warning.
+    Warn,
+    /// The lint is reported as an error.
+    Deny,
+}
+
+/// Overrides the level of some lints for the files matching some paths or the items of some
+/// modules.
+#[derive(Debug, Clone)]
+pub struct LintOverride {
+    /// Globs of the files, relative to the root of the package.
+    paths: GlobSet,
+    /// Full paths of the modules (e.g. `my_package::tests`), their submodules are included.
+    modules: Vec<String>,
+    /// The overridden levels.
+    levels: Vec<(String, LintLevel)>,
+}
+
+impl LintOverride {
+    pub fn new(
+        paths: &[String],
+        modules: Vec<String>,
+        levels: Vec<(String, LintLevel)>,
+    ) -> Result<Self, globset::Error> {
+        let mut builder = GlobSetBuilder::new();
+        for path in paths {
+            builder.add(Glob::new(path)?);
+        }
+        Ok(Self {
+            paths: builder.build()?,
+            modules,
+            levels,
+        })
+    }
+
+    fn applies_to(&self, file_path: Option<&Path>, module_path: &str) -> bool {
+        file_path.is_some_and(|path| self.paths.is_match(path))
+            || self.modules.iter().any(|module| {
+                module_path == module
+                    || module_path
+                        .strip_prefix(module.as_str())
+                        .is_some_and(|rest| rest.starts_with("::"))
+            })
+    }
+}
+
+/// Lint configuration of a package.
+#[derive(Debug, Clone, Default)]
+pub struct LintConfig {
+    /// Root of the package, the paths of the overrides are relative to it.
+    pub root: PathBuf,
+    /// The overrides, when several of them apply to the same lint the last one wins.
+    pub overrides: Vec<LintOverride>,
+    /// Names or full paths of the functions checking the caller of an entry point, in addition to
+    /// the default ones (`assert_only_owner` and `assert_only_role`).
+    pub guard_functions: Vec<String>,
+    /// Names or full paths of the functions asserting that an address isn't zero, in addition to
+    /// the `is_zero()` and `is_non_zero()` checks.
+    pub zero_address_guard_functions: Vec<String>,
+    /// Whether to report the arguments of `#[allow(...)]` that don't suppress any diagnostic. Off
+    /// by default, unlike the unfulfilled `#[expect(...)]` which are always reported.
+    pub unused_allow: bool,
+}
+
+impl LintConfig {
+    /// Gets the level of a lint in the file at `file_path`, in the module at `module_path`, if it's
+    /// overridden.
+    pub fn lint_level(
+        &self,
+        lint_name: &str,
+        file_path: &Path,
+        module_path: &str,
+    ) -> Option<LintLevel> {
+        let relative_path = file_path.strip_prefix(&self.root).ok();
+        self.overrides
+            .iter()
+            .rev()
+            .filter(|lint_override| lint_override.applies_to(relative_path, module_path))
+            .flat_map(|lint_override| lint_override.levels.iter().rev())
+            .find_map(|(name, level)| (name == lint_name).then_some(*level))
+    }
+
+    /// Applies the overridden level of the lint to the diagnostic. Returns `None` if the lint is
+    /// allowed.
+    pub fn apply_level(
+        &self,
+        lint_name: &str,
+        file_path: &Path,
+        module_path: &str,
+        diagnostic: PluginDiagnostic,
+    ) -> Option<PluginDiagnostic> {
+        let severity = match self.lint_level(lint_name, file_path, module_path) {
+            Some(LintLevel::Allow) => return None,
+            Some(LintLevel::Warn) => Severity::Warning,
+            Some(LintLevel::Deny) => Severity::Error,
+            None => diagnostic.severity,
+        };
+        Some(PluginDiagnostic {
+            severity,
+            ..diagnostic
+        })
+    }
+}
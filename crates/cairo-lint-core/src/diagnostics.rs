@@ -1,14 +1,127 @@
-use annotate_snippets::{Level, Renderer, Snippet};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use annotate_snippets::{Level, Message, Renderer, Snippet};
 use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
 use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::SemanticDiagnostic;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_utils::Upcast;
 
+use crate::fix::{fix_semantic_diagnostic, Fix};
+
+const FIX_HELP: &str = "consider applying the suggested fix";
+
+/// A location related to a diagnostic, other than the one it is reported at.
+#[derive(Debug, Clone)]
+pub struct SecondaryLabel {
+    pub span: TextSpan,
+    pub label: String,
+}
+
+/// Additional information pointing at where the evidence of a diagnostic comes from.
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticDetails {
+    /// Secondary locations, labelled with how they relate to the diagnostic.
+    pub labels: Vec<SecondaryLabel>,
+    /// Notes displayed after the source snippet.
+    pub notes: Vec<String>,
+}
+
+/// Details attached by a lint to one of its diagnostics, identified by its location and message.
+#[derive(Debug, Clone)]
+pub struct AttachedDetails {
+    pub stable_ptr: SyntaxStablePtrId,
+    pub message: String,
+    pub details: DiagnosticDetails,
+}
+
+impl AttachedDetails {
+    pub fn new(diagnostic: &PluginDiagnostic, details: DiagnosticDetails) -> Self {
+        Self {
+            stable_ptr: diagnostic.stable_ptr,
+            message: diagnostic.message.clone(),
+            details,
+        }
+    }
+}
+
+/// The details attached by the lints to their diagnostics, collected by the plugin while linting
+/// the modules and shared with the renderer of the diagnostics.
+#[derive(Debug, Clone, Default)]
+pub struct LintDetails(Arc<Mutex<HashMap<ModuleId, Vec<AttachedDetails>>>>);
+
+impl LintDetails {
+    /// Sets the details of the diagnostics of the module, replacing the ones of a previous run.
+    pub(crate) fn set_module_details(&self, module_id: ModuleId, details: Vec<AttachedDetails>) {
+        self.0.lock().unwrap().insert(module_id, details);
+    }
+
+    /// Gets the secondary labels and notes attached to a diagnostic by the lint reporting it. Lints
+    /// reporting a single location don't attach any.
+    pub fn get(&self, db: &RootDatabase, diagnostic: &SemanticDiagnostic) -> DiagnosticDetails {
+        let SemanticDiagnosticKind::PluginDiagnostic(plugin_diag) = &diagnostic.kind else {
+            return DiagnosticDetails::default();
+        };
+        let file_id = StableLocation::new(plugin_diag.stable_ptr).file_id(db.upcast());
+        // The related locations are only relevant if they're in the same file as the diagnostic.
+        if file_id != diagnostic.location(db.upcast()).file_id {
+            return DiagnosticDetails::default();
+        }
+        self.0
+            .lock()
+            .unwrap()
+            .values()
+            .flatten()
+            .find(|attached| {
+                attached.stable_ptr == plugin_diag.stable_ptr
+                    && attached.message == plugin_diag.message
+            })
+            .map_or_else(DiagnosticDetails::default, |attached| {
+                attached.details.clone()
+            })
+    }
+}
+
+/// Formats the diagnostic, along with the details attached to it by its lint in `details`.
 pub fn format_diagnostic<'a>(
     diagnostic: &'a SemanticDiagnostic,
     db: &'a RootDatabase,
     renderer: &Renderer,
+    details: &LintDetails,
+) -> String {
+    render_diagnostic(diagnostic, db, renderer, details, &[])
+}
+
+/// Formats the diagnostic like [`format_diagnostic`] and, if the diagnostic can be fixed, appends
+/// a `help:` footer showing the code as it would look like after running `--fix`.
+pub fn format_diagnostic_with_fix<'a>(
+    diagnostic: &'a SemanticDiagnostic,
+    db: &'a RootDatabase,
+    renderer: &Renderer,
+    details: &LintDetails,
+) -> String {
+    render_diagnostic(
+        diagnostic,
+        db,
+        renderer,
+        details,
+        &fix_semantic_diagnostic(db, diagnostic),
+    )
+}
+
+fn render_diagnostic(
+    diagnostic: &SemanticDiagnostic,
+    db: &RootDatabase,
+    renderer: &Renderer,
+    details: &LintDetails,
+    fixes: &[Fix],
 ) -> String {
     let location = diagnostic.location(db.upcast());
     let file_id = location.file_id;
@@ -17,17 +130,94 @@ pub fn format_diagnostic<'a>(
         Severity::Warning => Level::Warning,
         Severity::Error => Level::Error,
     };
-    let res = renderer
-        .render(
-            level.title(&diagnostic.format(db)).snippet(
-                Snippet::source(db.file_content(file_id).unwrap().as_ref())
-                    // We give the wole file as string input so the start line is 1
-                    .line_start(1)
-                    .origin(&file_id.full_path(db.upcast()))
-                    .fold(true)
-                    .annotation(level.span(span.to_str_range())),
+    let title = diagnostic.format(db);
+    let file_content = db.file_content(file_id).unwrap();
+    let origin = file_id.full_path(db.upcast());
+    let suggestion = FixSuggestion::new(&file_content, fixes);
+    let details = details.get(db, diagnostic);
+
+    let mut message = level.title(&title).snippet(
+        Snippet::source(file_content.as_ref())
+            // We give the wole file as string input so the start line is 1
+            .line_start(1)
+            .origin(&origin)
+            .fold(true)
+            .annotation(level.span(span.to_str_range()))
+            .annotations(
+                details
+                    .labels
+                    .iter()
+                    // The labels only give context, they're not issues on their own.
+                    .map(|label| {
+                        Level::Info
+                            .span(label.span.to_str_range())
+                            .label(&label.label)
+                    }),
             ),
-        )
-        .to_string();
+    );
+    message = message.footers(details.notes.iter().map(|note| Level::Note.title(note)));
+    if let Some(suggestion) = &suggestion {
+        message = message.footer(suggestion.to_message(&origin));
+    }
+    let res = renderer.render(message).to_string();
     format!("{}\n", res)
 }
+
+/// The lines touched by a fix, rewritten with the fix applied.
+struct FixSuggestion {
+    /// The rewritten lines.
+    source: String,
+    /// The line number of the first rewritten line in the original file.
+    line_start: usize,
+    /// The range of the replacement in `source`, without its surrounding whitespaces.
+    range: std::ops::Range<usize>,
+}
+
+impl FixSuggestion {
+    /// Builds the suggestion of the edits of a fix, if any. The edits are rendered as a single
+    /// replacement spanning all of them.
+    fn new(file_content: &str, fixes: &[Fix]) -> Option<Self> {
+        let fix_start = fixes
+            .iter()
+            .map(|fix| fix.span.to_str_range().start)
+            .min()?;
+        let fix_end = fixes.iter().map(|fix| fix.span.to_str_range().end).max()?;
+        let mut fix = file_content[fix_start..fix_end].to_string();
+        let mut edits = fixes.iter().collect::<Vec<_>>();
+        edits.sort_by_key(|edit| std::cmp::Reverse(edit.span.start));
+        for edit in edits {
+            let range = edit.span.to_str_range();
+            fix.replace_range(
+                range.start - fix_start..range.end - fix_start,
+                &edit.suggestion,
+            );
+        }
+
+        let lines_start = file_content[..fix_start]
+            .rfind('\n')
+            .map_or(0, |pos| pos + 1);
+        let lines_end = file_content[fix_end..]
+            .find('\n')
+            .map_or(file_content.len(), |pos| fix_end + pos);
+        let prefix = &file_content[lines_start..fix_start];
+        let suffix = &file_content[fix_end..lines_end];
+
+        let start = prefix.len() + (fix.len() - fix.trim_start().len());
+        let end = (prefix.len() + fix.trim_end().len()).max(start);
+        Some(Self {
+            source: format!("{prefix}{fix}{suffix}"),
+            line_start: file_content[..lines_start].matches('\n').count() + 1,
+            range: start..end,
+        })
+    }
+
+    fn to_message<'a>(&'a self, origin: &'a str) -> Message<'a> {
+        Level::Help.title(FIX_HELP).snippet(
+            Snippet::source(&self.source)
+                .line_start(self.line_start)
+                .origin(origin)
+                .fold(true)
+                .annotation(Level::Help.span(self.range.clone())),
+        )
+    }
+}
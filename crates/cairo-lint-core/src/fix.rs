@@ -1,28 +1,37 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::SemanticDiagnostic;
 use cairo_lang_syntax::node::ast::{
-    BlockOrIf, Condition, Expr, ExprBinary, ExprIf, ExprLoop, ExprMatch, OptionElseClause,
-    OptionPatternEnumInnerPattern, Pattern, Statement,
+    BinaryOperator, BlockOrIf, Condition, Expr, ExprBinary, ExprIf, ExprLoop, ExprMatch,
+    OptionElseClause, OptionExprClause, OptionPatternEnumInnerPattern, OptionTerminalSemicolon,
+    Param, PathSegmentWithGenericArgs, Pattern, Statement, StatementList,
 };
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::kind::SyntaxKind;
-use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 use cairo_lang_utils::Upcast;
 use if_chain::if_chain;
 use log::debug;
 
 use crate::lints::bool_comparison::generate_fixed_text_for_comparison;
-use crate::lints::double_comparison;
 use crate::lints::single_match::is_expr_unit;
+use crate::lints::{array_init_then_append, array_param_only_read, double_comparison, identity_op};
 use crate::plugin::{diagnostic_kind_from_message, CairoLintKind};
 
 mod import_fixes;
 pub use import_fixes::{apply_import_fixes, collect_unused_imports, ImportFix};
-mod helper;
-use helper::{invert_condition, remove_break_from_block, remove_break_from_else_clause};
+pub(crate) mod helper;
+use helper::{
+    invert_condition, negate_expr, remove_break_from_block, remove_break_from_else_clause,
+};
 
 /// Represents a fix for a diagnostic, containing the span of code to be replaced
 /// and the suggested replacement.
@@ -73,26 +82,79 @@ fn indent_snippet(input: &str, initial_indentation: usize) -> String {
 ///
 /// # Returns
 ///
-/// An `Option<(SyntaxNode, String)>` where the `SyntaxNode` represents the node to be
-/// replaced, and the `String` is the suggested replacement. Returns `None` if no fix
-/// is available for the given diagnostic.
-pub fn fix_semantic_diagnostic(
-    db: &RootDatabase,
-    diag: &SemanticDiagnostic,
-) -> Option<(SyntaxNode, String)> {
+/// The edits fixing the diagnostic, empty if no fix is available for the given diagnostic. A fix
+/// usually replaces a single node, but some of them are made of several disjoint edits so they
+/// don't overlap with the fixes of the surrounding code.
+pub fn fix_semantic_diagnostic(db: &RootDatabase, diag: &SemanticDiagnostic) -> Vec<Fix> {
     match diag.kind {
         SemanticDiagnosticKind::PluginDiagnostic(ref plugin_diag) => {
             Fixer.fix_plugin_diagnostic(db, plugin_diag)
         }
         SemanticDiagnosticKind::UnusedImport(_) => {
             debug!("Unused imports should be handled in preemptively");
-            None
+            Vec::new()
         }
         _ => {
             debug!("No fix available for diagnostic: {:?}", diag.kind);
-            None
+            Vec::new()
+        }
+    }
+}
+
+/// Gets the fixes of the diagnostics, grouped by the file they edit. Each fix is made of the edits
+/// of a single diagnostic (or unused import), which must be applied as a whole with
+/// [`apply_fixes`].
+pub fn collect_fixes(
+    db: &RootDatabase,
+    diagnostics: &[SemanticDiagnostic],
+) -> HashMap<FileId, Vec<Vec<Fix>>> {
+    // Handling unused imports separately as we need to run pre-analysis on the diagnostics to
+    // handle complex cases.
+    let mut fixes: HashMap<FileId, Vec<Vec<Fix>>> = HashMap::new();
+    for (file_id, import_fixes) in collect_unused_imports(db, diagnostics) {
+        fixes.entry(file_id).or_default().extend(
+            apply_import_fixes(db, &import_fixes)
+                .into_iter()
+                .map(|fix| vec![fix]),
+        );
+    }
+    for diag in diagnostics {
+        if matches!(diag.kind, SemanticDiagnosticKind::UnusedImport(_)) {
+            continue;
+        }
+        let diag_fixes = fix_semantic_diagnostic(db, diag);
+        if !diag_fixes.is_empty() {
+            let file_id = diag.location(db.upcast()).file_id;
+            fixes.entry(file_id).or_default().push(diag_fixes);
+        }
+    }
+    fixes
+}
+
+/// Applies the fixes to the content of a file.
+///
+/// Fixing nested diagnostics is a nightmare, so a fix overlapping an already applied one is
+/// skipped, along with all its edits so it's never half applied. The innermost fixes are applied
+/// first.
+pub fn apply_fixes(content: &str, mut fixes: Vec<Vec<Fix>>) -> String {
+    fixes.sort_by_key(|edits| Reverse(edits.iter().map(|edit| edit.span.start).min()));
+    let mut applied: Vec<Fix> = Vec::new();
+    for edits in fixes {
+        let overlaps = edits.iter().any(|edit| {
+            applied.iter().any(|applied_edit| {
+                edit.span.start < applied_edit.span.end && applied_edit.span.start < edit.span.end
+            })
+        });
+        if !overlaps {
+            applied.extend(edits);
         }
     }
+    applied.sort_by_key(|edit| Reverse(edit.span.start));
+    let mut content = content.to_string();
+    for edit in applied {
+        content.replace_range(edit.span.to_str_range(), &edit.suggestion);
+    }
+    content
 }
 
 #[derive(Default)]
@@ -179,21 +241,22 @@ impl Fixer {
     ///
     /// # Returns
     ///
-    /// An `Option<(SyntaxNode, String)>` containing the node to be replaced and the
-    /// suggested replacement.
+    /// The edits fixing the diagnostic, see [`fix_semantic_diagnostic`].
     pub fn fix_plugin_diagnostic(
         &self,
         db: &RootDatabase,
         plugin_diag: &PluginDiagnostic,
-    ) -> Option<(SyntaxNode, String)> {
-        match diagnostic_kind_from_message(&plugin_diag.message) {
+    ) -> Vec<Fix> {
+        let fix = match diagnostic_kind_from_message(&plugin_diag.message) {
             CairoLintKind::DoubleParens => {
                 self.fix_double_parens(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
             }
             CairoLintKind::DestructMatch => {
                 self.fix_destruct_match(db, plugin_diag.stable_ptr.lookup(db.upcast()))
             }
-            CairoLintKind::DoubleComparison => {
+            CairoLintKind::SimplifiableComparison
+            | CairoLintKind::RedundantComparison
+            | CairoLintKind::ContradictoryComparison => {
                 self.fix_double_comparison(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
             }
             CairoLintKind::EquatableIfLet => {
@@ -222,6 +285,27 @@ impl Fixer {
             CairoLintKind::ManualUnwrapOrDefault => {
                 self.fix_manual_unwrap_or_default(db, plugin_diag.stable_ptr.lookup(db.upcast()))
             }
+            CairoLintKind::ManualUnwrapOr => {
+                self.fix_manual_unwrap_or(db, plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::ManualUnwrapOrElse => {
+                self.fix_manual_unwrap_or_else(db, plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::ManualMap => {
+                self.fix_manual_map(db, plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::ManualMapErr => {
+                self.fix_manual_map_err(db, plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::ManualAndThen => {
+                self.fix_manual_and_then(db, plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::ManualFilter => {
+                self.fix_manual_filter(db, plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::ManualFlatten => {
+                self.fix_manual_flatten(db, plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
             CairoLintKind::LoopForWhile => {
                 self.fix_loop_break(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
             }
@@ -280,8 +364,128 @@ impl Fixer {
                     plugin_diag.stable_ptr.lookup(db.upcast()),
                 ),
             ),
+            CairoLintKind::ArrayInitThenAppend => {
+                return self
+                    .fix_array_init_then_append(
+                        db.upcast(),
+                        plugin_diag.stable_ptr.lookup(db.upcast()),
+                    )
+                    .into_iter()
+                    .collect();
+            }
+            CairoLintKind::LosslessTryIntoUnwrap => self.fix_lossless_try_into_unwrap(
+                db.upcast(),
+                plugin_diag.stable_ptr.lookup(db.upcast()),
+            ),
+            CairoLintKind::NeedlessReturn => {
+                self.fix_needless_return(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::LetAndReturn => {
+                return self
+                    .fix_let_and_return(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
+                    .into_iter()
+                    .collect();
+            }
+            CairoLintKind::NeedlessBool => {
+                self.fix_needless_bool(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::NonminimalBool => {
+                self.fix_nonminimal_bool(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::IdentityOp => {
+                self.fix_identity_op(db.upcast(), plugin_diag.stable_ptr.lookup(db.upcast()))
+            }
+            CairoLintKind::ArrayParamOnlyRead => {
+                return self
+                    .fix_array_param_only_read(db, plugin_diag.stable_ptr.lookup(db.upcast()));
+            }
+            CairoLintKind::RedundantClone => self.fix_redundant_clone(
+                db.upcast(),
+                ExprBinary::from_syntax_node(
+                    db.upcast(),
+                    plugin_diag.stable_ptr.lookup(db.upcast()),
+                ),
+            ),
             _ => None,
+        };
+        fix.map(|(node, suggestion)| Fix {
+            span: node.span(db.upcast()),
+            suggestion,
+        })
+        .into_iter()
+        .collect()
+    }
+
+    /// Rewrites the type of an `Array<T>` parameter only read by its function as `Span<T>`, and the
+    /// `array.span()` calls of the function as `array`. The `for` loops iterating over the
+    /// parameter get snapshots of its items, so they're copied at the start of the loop body. Each
+    /// of them is a separate edit, so they don't overlap with the fixes of the rest of the
+    /// function.
+    pub fn fix_array_param_only_read(&self, db: &RootDatabase, node: SyntaxNode) -> Vec<Fix> {
+        let syntax_db: &dyn SyntaxGroup = db.upcast();
+        let Some(param_node) = node.parent() else {
+            return Vec::new();
+        };
+        let param_name = Param::from_syntax_node(syntax_db, param_node.clone())
+            .name(syntax_db)
+            .text(syntax_db);
+        let Some((func_id, param_id)) =
+            array_param_only_read::reported_param(db, node.stable_ptr())
+        else {
+            return Vec::new();
+        };
+        let Ok(body) = db.function_body(func_id) else {
+            return Vec::new();
+        };
+        // The first generic path segment of the parameter is the `Array` of its type.
+        let Some(array_segment) = param_node.descendants(syntax_db).find(|descendant| {
+            descendant.kind(syntax_db) == SyntaxKind::PathSegmentWithGenericArgs
+        }) else {
+            return Vec::new();
+        };
+        let array_ident =
+            PathSegmentWithGenericArgs::from_syntax_node(syntax_db, array_segment).ident(syntax_db);
+        let mut fixes = vec![Fix {
+            span: array_ident.as_syntax_node().span_without_trivia(syntax_db),
+            suggestion: "Span".to_string(),
+        }];
+        for span_call in array_param_only_read::span_calls(db, &body.arenas, param_id) {
+            fixes.push(Fix {
+                span: span_call.lookup(syntax_db).span_without_trivia(syntax_db),
+                suggestion: param_name.to_string(),
+            });
+        }
+        for expr_for in array_param_only_read::param_loops(&body.arenas, param_id) {
+            let Some(item) = array_param_only_read::copied_item(db, &body.arenas, expr_for) else {
+                continue;
+            };
+            let Expr::Block(loop_body) = body.arenas.exprs[expr_for.body]
+                .stable_ptr()
+                .lookup(syntax_db)
+            else {
+                continue;
+            };
+            let indent = loop_body
+                .statements(syntax_db)
+                .elements(syntax_db)
+                .first()
+                .map_or_else(String::new, |statement| {
+                    node_indent(syntax_db, &statement.as_syntax_node())
+                });
+            let loop_start = loop_body
+                .lbrace(syntax_db)
+                .as_syntax_node()
+                .span_without_trivia(syntax_db)
+                .end;
+            fixes.push(Fix {
+                span: TextSpan {
+                    start: loop_start,
+                    end: loop_start,
+                },
+                suggestion: format!("\n{indent}let {name} = *{name};", name = item.name),
+            });
         }
+        fixes
     }
 
     /// Rewrites `break ();` as `break;` given the node text contains it.
@@ -602,6 +806,106 @@ impl Fixer {
             ),
         ))
     }
+
+    /// Rewrites a manual implementation of unwrap_or
+    pub fn fix_manual_unwrap_or(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<(SyntaxNode, String)> {
+        let (indent, option_var_name, fallback) = expr_get_indent_var_name_and_fallback(db, &node);
+        Some((
+            node,
+            format!("{indent}{option_var_name}.unwrap_or({fallback})"),
+        ))
+    }
+
+    /// Rewrites a manual implementation of unwrap_or_else, the fallback is moved in a closure
+    pub fn fix_manual_unwrap_or_else(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<(SyntaxNode, String)> {
+        let (indent, option_var_name, fallback) = expr_get_indent_var_name_and_fallback(db, &node);
+        Some((
+            node,
+            format!("{indent}{option_var_name}.unwrap_or_else(|| {fallback})"),
+        ))
+    }
+
+    /// Rewrites a manual implementation of map
+    pub fn fix_manual_map(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<(SyntaxNode, String)> {
+        let (indent, var_name, param, arm_expr) = expr_get_indent_var_name_and_arm(db, &node, 0);
+        let value = enum_variant_value(db, arm_expr);
+        Some((node, format!("{indent}{var_name}.map(|{param}| {value})")))
+    }
+
+    /// Rewrites a manual implementation of map_err
+    pub fn fix_manual_map_err(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<(SyntaxNode, String)> {
+        let (indent, var_name, param, arm_expr) = expr_get_indent_var_name_and_arm(db, &node, 1);
+        let value = enum_variant_value(db, arm_expr);
+        Some((
+            node,
+            format!("{indent}{var_name}.map_err(|{param}| {value})"),
+        ))
+    }
+
+    /// Rewrites a manual implementation of and_then
+    pub fn fix_manual_and_then(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<(SyntaxNode, String)> {
+        let (indent, var_name, param, arm_expr) = expr_get_indent_var_name_and_arm(db, &node, 0);
+        Some((
+            node,
+            format!(
+                "{indent}{var_name}.and_then(|{param}| {})",
+                arm_expr.as_syntax_node().get_text_without_trivia(db)
+            ),
+        ))
+    }
+
+    /// Rewrites a manual implementation of filter, the condition becomes the predicate
+    pub fn fix_manual_filter(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<(SyntaxNode, String)> {
+        let (indent, var_name, param, arm_expr) = expr_get_indent_var_name_and_arm(db, &node, 0);
+        let Expr::If(inner_if) = arm_expr else {
+            panic!("Expected an if expression");
+        };
+        let Condition::Expr(condition) = inner_if.condition(db) else {
+            panic!("Expected a boolean condition");
+        };
+        // The predicate of `filter` takes a snapshot of the value.
+        Some((
+            node,
+            format!(
+                "{indent}{var_name}.filter(|{param}| {})",
+                desnap_variable(db, &condition.expr(db).as_syntax_node(), &param)
+            ),
+        ))
+    }
+
+    /// Rewrites a manual implementation of flatten
+    pub fn fix_manual_flatten(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<(SyntaxNode, String)> {
+        Some((node.clone(), fix_manual("flatten", db, node)))
+    }
+
     /// Converts a `loop` with a conditionally-breaking `if` statement into a `while` loop.
     ///
     /// This function transforms loops that have a conditional `if` statement
@@ -970,6 +1274,240 @@ impl Fixer {
         let fix = format!("{} < {} ", lhs.trim(), rhs.trim());
         Some((node.as_syntax_node(), fix))
     }
+
+    /// Rewrites `let mut a = ArrayTrait::new();` followed by `a.append(x);` statements as
+    /// `let mut a = array![x, ...];`. As the fix spans several statements, it replaces the text
+    /// from the `let` statement to the last `append` one.
+    pub fn fix_array_init_then_append(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<Fix> {
+        let Statement::Let(statement_let) = Statement::from_syntax_node(db, node.clone()) else {
+            panic!("Expected a let statement");
+        };
+        let Pattern::Identifier(pattern) = statement_let.pattern(db) else {
+            panic!("Expected a variable pattern");
+        };
+        let array_name = pattern.name(db).text(db);
+        let statements = StatementList::from_syntax_node(db, node.parent()?).elements(db);
+        let let_index = statements
+            .iter()
+            .position(|statement| statement.as_syntax_node() == node)?;
+        let appends = statements[let_index + 1..]
+            .iter()
+            .map_while(|statement| {
+                array_init_then_append::appended_value(db, statement, &array_name)
+                    .map(|value| (statement, value))
+            })
+            .collect::<Vec<_>>();
+        let (last_append, _) = appends.last()?;
+        let values = appends
+            .iter()
+            .map(|(_, value)| value.as_syntax_node().get_text_without_trivia(db))
+            .collect::<Vec<_>>();
+
+        // Replaces the initializer of the let statement, keeping the rest of its text.
+        let let_range = node.span(db).to_str_range();
+        let rhs_range = statement_let
+            .rhs(db)
+            .as_syntax_node()
+            .span_without_trivia(db)
+            .to_str_range();
+        let let_text = node.get_text(db);
+        Some(Fix {
+            span: TextSpan {
+                start: node.span(db).start,
+                end: last_append.as_syntax_node().span(db).end,
+            },
+            suggestion: format!(
+                "{}array![{}]{}",
+                &let_text[..rhs_range.start - let_range.start],
+                values.join(", "),
+                &let_text[rhs_range.end - let_range.start..]
+            ),
+        })
+    }
+
+    /// Rewrites `x.try_into().unwrap()` as `x.into()` when the conversion can't fail.
+    pub fn fix_lossless_try_into_unwrap(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<(SyntaxNode, String)> {
+        let Expr::Binary(unwrap_call) = Expr::from_syntax_node(db, node.clone()) else {
+            return None;
+        };
+        let Expr::Binary(try_into_call) = unwrap_call.lhs(db) else {
+            return None;
+        };
+        if try_into_call
+            .rhs(db)
+            .as_syntax_node()
+            .get_text_without_trivia(db)
+            != "try_into()"
+        {
+            return None;
+        }
+        let text = node.get_text(db);
+        let trailing_trivia = &text[text.trim_end().len()..];
+        Some((
+            node,
+            format!(
+                "{}.into(){trailing_trivia}",
+                try_into_call.lhs(db).as_syntax_node().get_text(db)
+            ),
+        ))
+    }
+
+    /// Rewrites an identity operation as its operand, e.g. `x + 0` as `x`.
+    pub fn fix_identity_op(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<(SyntaxNode, String)> {
+        // Explicit calls, e.g. `Add::add(x, 0)`, aren't rewritten.
+        if node.kind(db) != SyntaxKind::ExprBinary {
+            return None;
+        }
+        let node_text = node.get_text(db);
+        let leading_trivia = &node_text[..node_text.len() - node_text.trim_start().len()];
+        let trailing_trivia = &node_text[node_text.trim_end().len()..];
+        let operand =
+            identity_op::kept_operand(db, &ExprBinary::from_syntax_node(db, node.clone()))?
+                .as_syntax_node()
+                .get_text_without_trivia(db);
+        Some((node, format!("{leading_trivia}{operand}{trailing_trivia}")))
+    }
+
+    /// Rewrites `variable.clone()` as `variable`.
+    pub fn fix_redundant_clone(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: ExprBinary,
+    ) -> Option<(SyntaxNode, String)> {
+        let node_text = node.as_syntax_node().get_text(db);
+        let leading_trivia = &node_text[..node_text.len() - node_text.trim_start().len()];
+        let trailing_trivia = &node_text[node_text.trim_end().len()..];
+        let variable = node.lhs(db).as_syntax_node().get_text_without_trivia(db);
+        Some((
+            node.as_syntax_node(),
+            format!("{leading_trivia}{variable}{trailing_trivia}"),
+        ))
+    }
+
+    /// Rewrites `return value;` at the end of a function as the tail expression `value`, and
+    /// removes a `return;` at the end of a function returning unit.
+    pub fn fix_needless_return(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<(SyntaxNode, String)> {
+        let Statement::Return(statement_return) = Statement::from_syntax_node(db, node.clone())
+        else {
+            panic!("Expected a return statement");
+        };
+        let OptionExprClause::ExprClause(expr_clause) = statement_return.expr_clause(db) else {
+            return Some((node, String::new()));
+        };
+        let (leading_trivia, trailing_trivia) = trivia(db, &node);
+        let value = expr_clause
+            .expr(db)
+            .as_syntax_node()
+            .get_text_without_trivia(db);
+        Some((node, format!("{leading_trivia}{value}{trailing_trivia}")))
+    }
+
+    /// Rewrites this:
+    ///
+    /// ```ignore
+    /// let res = compute();
+    /// res
+    /// ```
+    /// to this:
+    /// ```ignore
+    /// compute()
+    /// ```
+    pub fn fix_let_and_return(&self, db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<Fix> {
+        let Statement::Let(statement_let) = Statement::from_syntax_node(db, node.clone()) else {
+            panic!("Expected a let statement");
+        };
+        let statements = StatementList::from_syntax_node(db, node.parent()?).elements(db);
+        let let_index = statements
+            .iter()
+            .position(|statement| statement.as_syntax_node() == node)?;
+        let tail = statements.get(let_index + 1)?.as_syntax_node();
+
+        let (leading_trivia, _) = trivia(db, &node);
+        let (_, trailing_trivia) = trivia(db, &tail);
+        let value = statement_let
+            .rhs(db)
+            .as_syntax_node()
+            .get_text_without_trivia(db);
+        Some(Fix {
+            span: TextSpan {
+                start: node.span(db).start,
+                end: tail.span(db).end,
+            },
+            suggestion: format!("{leading_trivia}{value}{trailing_trivia}"),
+        })
+    }
+
+    /// Rewrites `if c { true } else { false }` as `c` and `if c { false } else { true }` as the
+    /// negation of `c`.
+    pub fn fix_needless_bool(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<(SyntaxNode, String)> {
+        let expr_if = ExprIf::from_syntax_node(db, node.clone());
+        let Condition::Expr(condition) = expr_if.condition(db) else {
+            panic!("Expected a boolean condition");
+        };
+        let condition = condition.expr(db);
+        let is_negated = matches!(
+            expr_if.if_block(db).statements(db).elements(db).as_slice(),
+            [Statement::Expr(statement_expr)] if matches!(statement_expr.expr(db), Expr::False(_))
+        );
+        let (leading_trivia, trailing_trivia) = trivia(db, &node);
+        let fix = if is_negated {
+            negate_expr(db, &condition)
+        } else {
+            condition.as_syntax_node().get_text_without_trivia(db)
+        };
+        Some((node, format!("{leading_trivia}{fix}{trailing_trivia}")))
+    }
+
+    /// Rewrites a negated comparison as the opposite comparison, e.g. `!(a == b)` as `a != b`,
+    /// and a double negation `!!x` as `x`.
+    pub fn fix_nonminimal_bool(
+        &self,
+        db: &dyn SyntaxGroup,
+        node: SyntaxNode,
+    ) -> Option<(SyntaxNode, String)> {
+        let Expr::Unary(expr_unary) = Expr::from_syntax_node(db, node.clone()) else {
+            return None;
+        };
+        let (leading_trivia, trailing_trivia) = trivia(db, &node);
+        Some((
+            node,
+            format!(
+                "{leading_trivia}{}{trailing_trivia}",
+                negate_expr(db, &expr_unary.expr(db))
+            ),
+        ))
+    }
+}
+
+/// Gets the leading and trailing trivia (whitespaces and comments) of the node.
+fn trivia(db: &dyn SyntaxGroup, node: &SyntaxNode) -> (String, String) {
+    let text = node.get_text(db);
+    let range = node.span(db).to_str_range();
+    let range_without_trivia = node.span_without_trivia(db).to_str_range();
+    (
+        text[..range_without_trivia.start - range.start].to_string(),
+        text[range_without_trivia.end - range.start..].to_string(),
+    )
 }
 
 fn expr_match_get_var_name_and_err(
@@ -1035,6 +1573,161 @@ fn expr_if_get_var_name_and_err(expr_if: ExprIf, db: &dyn SyntaxGroup) -> (Strin
     (option_var_name, err)
 }
 
+/// Gets the indentation, the matched option and the fallback of a manual `unwrap_or` written as a
+/// `match` or an `if let`. A block only made of its tail expression is replaced by the expression.
+fn expr_get_indent_var_name_and_fallback(
+    db: &dyn SyntaxGroup,
+    node: &SyntaxNode,
+) -> (String, String, String) {
+    let (option_var, fallback) = match Expr::from_syntax_node(db, node.clone()) {
+        Expr::Match(expr_match) => {
+            let arms = expr_match.arms(db).elements(db);
+            let [_, none_arm] = &arms[..] else {
+                panic!("Expected exactly two arms in the match expression");
+            };
+            (expr_match.expr(db), none_arm.expression(db))
+        }
+        Expr::If(expr_if) => {
+            let Condition::Let(condition_let) = expr_if.condition(db) else {
+                panic!("Expected a ConditionLet condition");
+            };
+            let OptionElseClause::ElseClause(else_clause) = expr_if.else_clause(db) else {
+                panic!("Expected a non-empty else clause");
+            };
+            let fallback = match else_clause.else_block_or_if(db) {
+                BlockOrIf::Block(expr_block) => Expr::Block(expr_block),
+                BlockOrIf::If(expr_if) => Expr::If(expr_if),
+            };
+            (condition_let.expr(db), fallback)
+        }
+        _ => panic!("SyntaxKind should be either ExprIf or ExprMatch"),
+    };
+
+    (
+        node_indent(db, node),
+        option_var.as_syntax_node().get_text_without_trivia(db),
+        block_tail_or_expr(db, fallback)
+            .as_syntax_node()
+            .get_text_without_trivia(db),
+    )
+}
+
+/// Gets the indentation, the matched expression, the inner pattern of the arm at `arm_index` and
+/// the expression of this arm, used to build the closure of a combinator. For an `if let`, the
+/// arm is the `if` block.
+fn expr_get_indent_var_name_and_arm(
+    db: &dyn SyntaxGroup,
+    node: &SyntaxNode,
+    arm_index: usize,
+) -> (String, String, String, Expr) {
+    let (var, pattern, arm_expr) = match Expr::from_syntax_node(db, node.clone()) {
+        Expr::Match(expr_match) => {
+            let arm = &expr_match.arms(db).elements(db)[arm_index];
+            (
+                expr_match.expr(db),
+                arm.patterns(db).elements(db)[0].clone(),
+                arm.expression(db),
+            )
+        }
+        Expr::If(expr_if) => {
+            let Condition::Let(condition_let) = expr_if.condition(db) else {
+                panic!("Expected a ConditionLet condition");
+            };
+            (
+                condition_let.expr(db),
+                condition_let.patterns(db).elements(db)[0].clone(),
+                Expr::Block(expr_if.if_block(db)),
+            )
+        }
+        _ => panic!("SyntaxKind should be either ExprIf or ExprMatch"),
+    };
+    let Pattern::Enum(enum_pattern) = pattern else {
+        panic!("Expected an enum pattern");
+    };
+    let OptionPatternEnumInnerPattern::PatternEnumInnerPattern(inner_pattern) =
+        enum_pattern.pattern(db)
+    else {
+        panic!("Expected an enum pattern with an inner pattern");
+    };
+    (
+        node_indent(db, node),
+        var.as_syntax_node().get_text_without_trivia(db),
+        inner_pattern
+            .pattern(db)
+            .as_syntax_node()
+            .get_text_without_trivia(db),
+        block_tail_or_expr(db, arm_expr),
+    )
+}
+
+/// Gets the text of the expression where the variable, bound to a snapshot, is desnapped, e.g.
+/// `*v > 10` for `v > 10`. Method calls on the variable are kept as is since their receiver is
+/// desnapped automatically.
+fn desnap_variable(db: &dyn SyntaxGroup, node: &SyntaxNode, var_name: &str) -> String {
+    let text = node.get_text_without_trivia(db);
+    let start = node.span_without_trivia(db).to_str_range().start;
+    let mut desnapped = String::new();
+    let mut end = 0;
+    for path in node.descendants(db).filter(|descendant| {
+        descendant.kind(db) == SyntaxKind::ExprPath
+            && descendant.get_text_without_trivia(db) == var_name
+    }) {
+        let receiver_of = path
+            .parent()
+            .filter(|parent| parent.kind(db) == SyntaxKind::ExprBinary)
+            .map(|parent| ExprBinary::from_syntax_node(db, parent))
+            .filter(|binary| {
+                matches!(binary.op(db), BinaryOperator::Dot(_))
+                    && binary.lhs(db).as_syntax_node() == path
+            });
+        let replacement = match receiver_of {
+            Some(binary) if matches!(binary.rhs(db), Expr::FunctionCall(_)) => continue,
+            Some(_) => format!("(*{var_name})"),
+            None => format!("*{var_name}"),
+        };
+        let range = path.span_without_trivia(db).to_str_range();
+        desnapped.push_str(&text[end..range.start - start]);
+        desnapped.push_str(&replacement);
+        end = range.end - start;
+    }
+    desnapped.push_str(&text[end..]);
+    desnapped
+}
+
+/// Gets the whitespaces before the node, to keep its indentation when it's rewritten.
+fn node_indent(db: &dyn SyntaxGroup, node: &SyntaxNode) -> String {
+    node.get_text(db)
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect()
+}
+
+/// Gets the tail expression of a block only made of its tail expression, or the expression itself
+/// if it's not such a block.
+fn block_tail_or_expr(db: &dyn SyntaxGroup, expr: Expr) -> Expr {
+    if let Expr::Block(expr_block) = &expr {
+        if let [Statement::Expr(statement_expr)] = &expr_block.statements(db).elements(db)[..] {
+            if matches!(
+                statement_expr.semicolon(db),
+                OptionTerminalSemicolon::Empty(_)
+            ) {
+                return block_tail_or_expr(db, statement_expr.expr(db));
+            }
+        }
+    }
+    expr
+}
+
+/// Gets the value wrapped in an enum variant, e.g. `x + 1` in `Option::Some(x + 1)`.
+fn enum_variant_value(db: &dyn SyntaxGroup, expr: Expr) -> String {
+    let Expr::FunctionCall(func_call) = expr else {
+        panic!("Expected an enum variant");
+    };
+    let args = func_call.arguments(db).arguments(db).elements(db);
+    let arg = args.first().expect("Should have arg");
+    arg.as_syntax_node().get_text_without_trivia(db)
+}
+
 pub fn fix_manual(func_name: &str, db: &dyn SyntaxGroup, node: SyntaxNode) -> String {
     match node.kind(db) {
         SyntaxKind::ExprMatch => {
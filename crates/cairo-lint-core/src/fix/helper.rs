@@ -8,11 +8,14 @@
 //! 1. Processing block and `else` clause content, including nested `if-else` constructs.
 //! 2. Inverting logical conditions to their opposite for loop and condition rewriting.
 //! 3. Skipping `break` statements when processing blocks to correctly transform loops.
+//! 4. Negating boolean expressions based on their syntax.
 //!
 //! These helper functions can be reused in various parts of the Cairo Lint codebase to maintain
 //! consistency and modularity when working with blocks and conditions.
 
-use cairo_lang_syntax::node::ast::{BlockOrIf, ElseClause, ExprBlock, Statement};
+use cairo_lang_syntax::node::ast::{
+    BinaryOperator, BlockOrIf, ElseClause, Expr, ExprBlock, Statement, UnaryOperator,
+};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::TypedSyntaxNode;
 
@@ -179,3 +182,53 @@ pub fn invert_simple_condition(condition: &str) -> String {
         format!("!({})", condition)
     }
 }
+
+/// Negates a boolean expression, simplifying the negation of comparisons, negations and boolean
+/// literals.
+///
+/// # Arguments
+///
+/// * `db` - The syntax group which provides access to the syntax tree.
+/// * `expr` - The boolean expression to negate.
+///
+/// # Returns
+///
+/// A string representing the negated expression.
+///
+/// # Example
+///
+/// Input: `x >= 5`, `!x`, `x`, `x && y`
+/// Output: `x < 5`, `x`, `!x`, `!(x && y)`
+pub fn negate_expr(db: &dyn SyntaxGroup, expr: &Expr) -> String {
+    let text = expr.as_syntax_node().get_text_without_trivia(db);
+    match expr {
+        Expr::Parenthesized(expr) => negate_expr(db, &expr.expr(db)),
+        Expr::True(_) => "false".to_string(),
+        Expr::False(_) => "true".to_string(),
+        Expr::Unary(unary) if matches!(unary.op(db), UnaryOperator::Not(_)) => {
+            unary.expr(db).as_syntax_node().get_text_without_trivia(db)
+        }
+        Expr::Binary(binary) => {
+            let inverted_op = match binary.op(db) {
+                BinaryOperator::EqEq(_) => "!=",
+                BinaryOperator::Neq(_) => "==",
+                BinaryOperator::LT(_) => ">=",
+                BinaryOperator::LE(_) => ">",
+                BinaryOperator::GT(_) => "<=",
+                BinaryOperator::GE(_) => "<",
+                // Method calls and member accesses bind tighter than `!`.
+                BinaryOperator::Dot(_) => return format!("!{text}"),
+                _ => return format!("!({text})"),
+            };
+            format!(
+                "{} {inverted_op} {}",
+                binary.lhs(db).as_syntax_node().get_text_without_trivia(db),
+                binary.rhs(db).as_syntax_node().get_text_without_trivia(db)
+            )
+        }
+        Expr::Path(_) | Expr::FunctionCall(_) | Expr::Indexed(_) | Expr::Unary(_) => {
+            format!("!{text}")
+        }
+        _ => format!("!({text})"),
+    }
+}
@@ -56,7 +56,7 @@ use crate::fix::Fix;
 /// A HashMap where keys are FileIds and values are HashMaps of SyntaxNodes to ImportFixes.
 pub fn collect_unused_imports(
     db: &RootDatabase,
-    diags: &Vec<SemanticDiagnostic>,
+    diags: &[SemanticDiagnostic],
 ) -> HashMap<FileId, HashMap<SyntaxNode, ImportFix>> {
     let mut file_fixes = HashMap::new();
 
@@ -1,4 +1,7 @@
+pub mod analysis;
+pub mod config;
 pub mod diagnostics;
 pub mod fix;
 pub mod lints;
 pub mod plugin;
+pub mod suppression;
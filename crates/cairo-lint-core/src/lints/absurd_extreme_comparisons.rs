@@ -0,0 +1,133 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::constant::ConstValue;
+use cairo_lang_semantic::{
+    Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, ExprSnapshot,
+};
+use cairo_lang_syntax::node::TypedStablePtr;
+use num_bigint::BigInt;
+
+use super::{function_trait_name_from_fn_id, int_range, EQ, GE, GT, INTO, LE, LT, NE};
+
+pub const ABSURD_COMPARISON_ALWAYS_TRUE: &str = "This comparison is always true because of the \
+                                                 bounds of the compared integer type.";
+pub const ABSURD_COMPARISON_ALWAYS_FALSE: &str = "This comparison is always false because of the \
+                                                  bounds of the compared integer type.";
+
+pub const ALLOWED: [&str; 1] = [LINT_NAME];
+pub(crate) const LINT_NAME: &str = "absurd_extreme_comparisons";
+
+/// Checks for comparisons between an integer and a constant that are always true or always false
+/// because of the range of the integer type, e.g. `x >= 0` on an unsigned integer or
+/// `x > Bounded::<u8>::MAX`.
+pub fn check_absurd_extreme_comparisons(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let func = function_trait_name_from_fn_id(db, &expr_func.function);
+    if ![LT, LE, GT, GE, EQ, NE].contains(&func.as_str()) {
+        return;
+    }
+    let [ExprFunctionCallArg::Value(lhs), ExprFunctionCallArg::Value(rhs)] =
+        expr_func.args.as_slice()
+    else {
+        return;
+    };
+    let (lhs, rhs) = (peel_snapshot(*lhs, arenas), peel_snapshot(*rhs, arenas));
+    // Puts the constant on the right side of the comparison.
+    let (operand, constant, func) = match (
+        constant_value(db, lhs, arenas),
+        constant_value(db, rhs, arenas),
+    ) {
+        (None, Some(constant)) => (lhs, constant, func.as_str()),
+        (Some(constant), None) => (
+            rhs,
+            constant,
+            match func.as_str() {
+                LT => GT,
+                LE => GE,
+                GT => LT,
+                GE => LE,
+                func => func,
+            },
+        ),
+        _ => return,
+    };
+    let Some((min, max)) = operand_range(db, operand, arenas) else {
+        return;
+    };
+    let always = match func {
+        LT if constant > max => Some(true),
+        LT if constant <= min => Some(false),
+        LE if constant >= max => Some(true),
+        LE if constant < min => Some(false),
+        GT if constant < min => Some(true),
+        GT if constant >= max => Some(false),
+        GE if constant <= min => Some(true),
+        GE if constant > max => Some(false),
+        EQ if constant < min || constant > max => Some(false),
+        NE if constant < min || constant > max => Some(true),
+        _ => None,
+    };
+    if let Some(always) = always {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: expr_func.stable_ptr.untyped(),
+            message: if always {
+                ABSURD_COMPARISON_ALWAYS_TRUE
+            } else {
+                ABSURD_COMPARISON_ALWAYS_FALSE
+            }
+            .to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+fn peel_snapshot(expr_id: ExprId, arenas: &Arenas) -> ExprId {
+    match &arenas.exprs[expr_id] {
+        Expr::Snapshot(ExprSnapshot { inner, .. }) => *inner,
+        _ => expr_id,
+    }
+}
+
+/// Gets the value of an integer literal or constant (e.g. `Bounded::MAX`).
+fn constant_value(db: &dyn SemanticGroup, expr_id: ExprId, arenas: &Arenas) -> Option<BigInt> {
+    match &arenas.exprs[expr_id] {
+        Expr::Literal(literal) => Some(literal.value.clone()),
+        Expr::Constant(constant) => {
+            let const_value = match db.lookup_intern_const_value(constant.const_value_id) {
+                ConstValue::ImplConstant(impl_constant_id) => db.lookup_intern_const_value(
+                    db.impl_constant_concrete_implized_value(impl_constant_id)
+                        .ok()?,
+                ),
+                const_value => const_value,
+            };
+            match const_value {
+                ConstValue::Int(value, _) => Some(value),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Gets the range of the values the operand can take. A value converted with `into` keeps the
+/// range of its original type.
+fn operand_range(
+    db: &dyn SemanticGroup,
+    expr_id: ExprId,
+    arenas: &Arenas,
+) -> Option<(BigInt, BigInt)> {
+    let expr = &arenas.exprs[expr_id];
+    if let Expr::FunctionCall(expr_func) = expr {
+        if let [ExprFunctionCallArg::Value(value)] = expr_func.args.as_slice() {
+            if function_trait_name_from_fn_id(db, &expr_func.function) == INTO {
+                return operand_range(db, *value, arenas);
+            }
+        }
+    }
+    int_range(db, expr.ty())
+}
@@ -0,0 +1,128 @@
+use cairo_lang_defs::ids::LocalVarId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::ids::FileKind;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{
+    Arenas, Expr, ExprBlock, ExprFunctionCallArg, ExprVarMemberPath, Pattern, Statement,
+    StatementId, VarId,
+};
+use cairo_lang_syntax::node::ast::{self, ArgClause, BinaryOperator, OptionTerminalSemicolon};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use if_chain::if_chain;
+
+use super::{function_trait_name_from_fn_id, ARRAY_APPEND, ARRAY_NEW};
+
+pub const ARRAY_INIT_THEN_APPEND: &str =
+    "`ArrayTrait::new()` followed by `append` calls. Consider using `array![...]` instead.";
+
+pub const ALLOWED: [&str; 1] = [LINT_NAME];
+pub(crate) const LINT_NAME: &str = "array_init_then_append";
+
+/// Checks for an array created with `ArrayTrait::new()` and then filled with `append` calls right
+/// after, which can be done with `array![...]`.
+///
+/// ```ignore
+/// let mut a = ArrayTrait::new();
+/// a.append(1);
+/// a.append(2);
+/// ```
+pub fn check_array_init_then_append(
+    db: &dyn SemanticGroup,
+    expr_block: &ExprBlock,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    for (index, statement_id) in expr_block.statements.iter().enumerate() {
+        let Statement::Let(statement_let) = &arenas.statements[*statement_id] else {
+            continue;
+        };
+        // The `array!` macro itself expands to `ArrayTrait::new()` followed by `append` calls.
+        if statement_let
+            .stable_ptr
+            .untyped()
+            .file_id(db.upcast())
+            .kind(db.upcast())
+            == FileKind::Expr
+        {
+            continue;
+        }
+        if_chain! {
+            if let Pattern::Variable(array_var) = &arenas.patterns[statement_let.pattern];
+            if let Expr::FunctionCall(init_call) = &arenas.exprs[statement_let.expr];
+            if function_trait_name_from_fn_id(db, &init_call.function) == ARRAY_NEW;
+            if let Some(next_statement_id) = expr_block.statements.get(index + 1);
+            if is_append_to(db, arenas, *next_statement_id, array_var.var.id, &array_var.name);
+            then {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: statement_let.stable_ptr.untyped(),
+                    message: ARRAY_INIT_THEN_APPEND.to_string(),
+                    severity: Severity::Warning,
+                });
+            }
+        }
+    }
+}
+
+/// Checks if the statement is a call to `append` on the array, which can be moved in `array![...]`.
+fn is_append_to(
+    db: &dyn SemanticGroup,
+    arenas: &Arenas,
+    statement_id: StatementId,
+    array_var: LocalVarId,
+    array_name: &str,
+) -> bool {
+    let Statement::Expr(statement_expr) = &arenas.statements[statement_id] else {
+        return false;
+    };
+    if_chain! {
+        if let Expr::FunctionCall(append_call) = &arenas.exprs[statement_expr.expr];
+        if function_trait_name_from_fn_id(db, &append_call.function) == ARRAY_APPEND;
+        if let [ExprFunctionCallArg::Reference(ExprVarMemberPath::Var(self_var)), _] =
+            append_call.args.as_slice();
+        if self_var.var == VarId::Local(array_var);
+        then {
+            let statement = statement_expr.stable_ptr.lookup(db.upcast());
+            return appended_value(db.upcast(), &statement, array_name).is_some();
+        }
+    }
+    false
+}
+
+/// Gets the value appended by a `array_name.append(value);` statement. Returns `None` if the
+/// statement has another shape, contains comments or if the value uses the array.
+pub fn appended_value(
+    db: &dyn SyntaxGroup,
+    statement: &ast::Statement,
+    array_name: &str,
+) -> Option<ast::Expr> {
+    let ast::Statement::Expr(statement_expr) = statement else {
+        return None;
+    };
+    let node = statement.as_syntax_node();
+    // Comments would be lost when merging the statement in `array![...]`.
+    if node.get_text(db).trim() != node.get_text_without_trivia(db) {
+        return None;
+    }
+    if_chain! {
+        if let OptionTerminalSemicolon::TerminalSemicolon(_) = statement_expr.semicolon(db);
+        if let ast::Expr::Binary(expr_binary) = statement_expr.expr(db);
+        if let BinaryOperator::Dot(_) = expr_binary.op(db);
+        if expr_binary.lhs(db).as_syntax_node().get_text_without_trivia(db) == array_name;
+        if let ast::Expr::FunctionCall(append_call) = expr_binary.rhs(db);
+        if append_call.path(db).as_syntax_node().get_text_without_trivia(db) == "append";
+        if let [arg] = append_call.arguments(db).arguments(db).elements(db).as_slice();
+        if arg.modifiers(db).elements(db).is_empty();
+        if let ArgClause::Unnamed(arg_clause) = arg.arg_clause(db);
+        then {
+            let value = arg_clause.value(db);
+            let uses_array = value.as_syntax_node().descendants(db).any(|node| {
+                node.kind(db) == SyntaxKind::TokenIdentifier && node.get_text(db) == array_name
+            });
+            return (!uses_array).then_some(value);
+        }
+    }
+    None
+}
@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{FunctionWithBodyId, ParamId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{
+    Arenas, Expr, ExprFor, ExprFunctionCall, ExprFunctionCallArg, ExprId, ExprVar, Mutability,
+    Pattern, PatternVariable, VarId,
+};
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use super::function_trait_name_from_fn_id;
+use super::starknet_security::is_abi_function;
+use crate::plugin::module_functions;
+
+pub const ARRAY_PARAM_ONLY_READ: &str =
+    "This array parameter is only read. Consider taking a `Span` instead, so callers don't have to \
+     give up or clone their array.";
+
+pub const ALLOWED: [&str; 1] = [LINT_NAME];
+pub(crate) const LINT_NAME: &str = "array_param_only_read";
+
+const ARRAY_TYPE: &str = "core::array::Array::<";
+const ARRAY_SPAN: &str = "core::array::ArrayTrait::span";
+/// Functions reading an array that are also available on a span, with the same method call syntax.
+const READ_FUNCTIONS: [&str; 5] = [
+    "core::array::ArrayTrait::len",
+    "core::array::ArrayTrait::at",
+    "core::array::ArrayTrait::get",
+    ARRAY_SPAN,
+    "core::ops::index::IndexView::index",
+];
+
+/// Checks for `Array<T>` parameters only used through `len`, `at`, `get`, `span`, an index or a
+/// `for` loop copying their items, e.g. `fn sum(values: Array<u32>)` only iterating over
+/// `values.span()`.
+///
+/// Only the free functions and the functions of `#[generate_trait]` impls are checked, as the
+/// signature of the other impl functions is imposed by their trait. The functions of the ABI of a
+/// contract (e.g. `#[external(v0)]` ones) are skipped too, as changing their signature would change
+/// the ABI.
+pub fn check_array_param_only_read(
+    db: &dyn SemanticGroup,
+    func_id: FunctionWithBodyId,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let has_own_signature = match func_id {
+        FunctionWithBodyId::Free(_) => true,
+        FunctionWithBodyId::Impl(impl_function_id) => impl_function_id
+            .impl_def_id(db.upcast())
+            .stable_ptr(db.upcast())
+            .lookup(db.upcast())
+            .has_attr(db.upcast(), "generate_trait"),
+        FunctionWithBodyId::Trait(_) => false,
+    };
+    if !has_own_signature || is_abi_function(db, func_id) {
+        return;
+    }
+    let Ok(signature) = db.function_with_body_signature(func_id) else {
+        return;
+    };
+    let read_uses = read_uses(db, arenas);
+    for param in signature.params {
+        if param.mutability != Mutability::Immutable || !param.ty.format(db).starts_with(ARRAY_TYPE)
+        {
+            continue;
+        }
+        let mut uses = arenas
+            .exprs
+            .iter()
+            .filter(|(_expr_id, expr)| is_param(expr, param.id))
+            .peekable();
+        let is_only_read =
+            uses.peek().is_some() && uses.all(|(expr_id, _expr)| read_uses.contains(&expr_id));
+        if is_only_read {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: param.stable_ptr.untyped(),
+                message: ARRAY_PARAM_ONLY_READ.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Gets the variables snapshotted to call one of the read functions with the method call syntax
+/// (e.g. `values.len()`) or with an index (e.g. `values[0]`), and the ones iterated over by a `for`
+/// loop copying their items.
+fn read_uses(db: &dyn SemanticGroup, arenas: &Arenas) -> HashSet<ExprId> {
+    let mut read_uses = HashSet::new();
+    for (_expr_id, expr) in &arenas.exprs {
+        match expr {
+            Expr::FunctionCall(expr_func) => {
+                if let Some(array) = method_call_array(db, arenas, expr_func, &READ_FUNCTIONS) {
+                    read_uses.insert(array);
+                }
+            }
+            Expr::For(expr_for) if copied_item(db, arenas, expr_for).is_some() => {
+                read_uses.insert(expr_for.expr_id);
+            }
+            _ => {}
+        }
+    }
+    read_uses
+}
+
+/// Gets the array snapshotted to call one of the functions with the method call syntax or with an
+/// index, e.g. `values` in `values.len()`.
+fn method_call_array(
+    db: &dyn SemanticGroup,
+    arenas: &Arenas,
+    expr_func: &ExprFunctionCall,
+    functions: &[&str],
+) -> Option<ExprId> {
+    let syntax_kind = expr_func
+        .stable_ptr
+        .untyped()
+        .lookup(db.upcast())
+        .kind(db.upcast());
+    if !matches!(
+        syntax_kind,
+        SyntaxKind::ExprBinary | SyntaxKind::ExprIndexed
+    ) || !functions.contains(&function_trait_name_from_fn_id(db, &expr_func.function).as_str())
+    {
+        return None;
+    }
+    match expr_func.args.first() {
+        Some(ExprFunctionCallArg::Value(array)) => match &arenas.exprs[*array] {
+            Expr::Snapshot(snapshot) => Some(snapshot.inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Gets the variable bound to the items of a `for` loop if they can be copied, e.g. `value` in
+/// `for value in values`. Iterating over a span gives snapshots of the items, so the loop keeps
+/// working by copying them.
+pub(crate) fn copied_item<'a>(
+    db: &dyn SemanticGroup,
+    arenas: &'a Arenas,
+    expr_for: &ExprFor,
+) -> Option<&'a PatternVariable> {
+    match &arenas.patterns[expr_for.pattern] {
+        Pattern::Variable(pattern)
+            if !pattern.var.is_mut && db.copyable(pattern.var.ty).is_ok() =>
+        {
+            Some(pattern)
+        }
+        _ => None,
+    }
+}
+
+/// Gets the function of the parameter reported by the lint, and its id.
+pub(crate) fn reported_param(
+    db: &dyn SemanticGroup,
+    stable_ptr: SyntaxStablePtrId,
+) -> Option<(FunctionWithBodyId, ParamId)> {
+    let file_id = stable_ptr.file_id(db.upcast());
+    db.file_modules(file_id)
+        .ok()?
+        .iter()
+        .flat_map(|module_id| module_functions(db, *module_id))
+        .find_map(|func_id| {
+            let signature = db.function_with_body_signature(func_id).ok()?;
+            let param = signature
+                .params
+                .into_iter()
+                .find(|param| param.stable_ptr.untyped() == stable_ptr)?;
+            Some((func_id, param.id))
+        })
+}
+
+/// Gets the `param.span()` calls of the function, which become `param` once the parameter is a
+/// span.
+pub(crate) fn span_calls(
+    db: &dyn SemanticGroup,
+    arenas: &Arenas,
+    param: ParamId,
+) -> Vec<SyntaxStablePtrId> {
+    arenas
+        .exprs
+        .iter()
+        .filter_map(|(_expr_id, expr)| match expr {
+            Expr::FunctionCall(expr_func) => {
+                let array = method_call_array(db, arenas, expr_func, &[ARRAY_SPAN])?;
+                is_param(&arenas.exprs[array], param).then_some(expr_func.stable_ptr.untyped())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Gets the `for` loops iterating over the parameter, which give snapshots of its items once it's
+/// a span.
+pub(crate) fn param_loops<'a>(arenas: &'a Arenas, param: ParamId) -> Vec<&'a ExprFor> {
+    arenas
+        .exprs
+        .iter()
+        .filter_map(|(_expr_id, expr)| match expr {
+            Expr::For(expr_for) if is_param(&arenas.exprs[expr_for.expr_id], param) => {
+                Some(expr_for)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_param(expr: &Expr, param: ParamId) -> bool {
+    matches!(expr, Expr::Var(ExprVar { var: VarId::Param(id), .. }) if *id == param)
+}
@@ -3,8 +3,7 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
-use cairo_lang_syntax::node::helpers::QueryAttrs;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::TypedStablePtr;
 use if_chain::if_chain;
 use num_bigint::BigInt;
 
@@ -14,7 +13,7 @@ pub const BITWISE_FOR_PARITY: &str =
     "You seem to be trying to use `&` for parity check. Consider using `DivRem::div_rem()` instead.";
 
 pub const ALLOWED: [&str; 1] = [LINT_NAME];
-const LINT_NAME: &str = "bitwise_for_parity_check";
+pub(crate) const LINT_NAME: &str = "bitwise_for_parity_check";
 
 /// Checks for `x & 1` which is unoptimized in cairo and can be replaced by `x % 1`
 pub fn check_bitwise_for_parity(
@@ -23,14 +22,6 @@ pub fn check_bitwise_for_parity(
     arenas: &Arenas,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    // Checks if the lint is allowed in any upper scope
-    let mut current_node = expr_func.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
     let Ok(Some(func_id)) = expr_func.function.get_concrete(db).body(db) else {
         return;
     };
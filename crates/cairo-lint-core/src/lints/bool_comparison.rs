@@ -5,16 +5,17 @@ use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
 use cairo_lang_syntax::node::ast::ExprBinary;
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 
+use crate::fix::helper::negate_expr;
+
 pub const BOOL_COMPARISON: &str =
     "Unnecessary comparison with a boolean value. Use the variable directly.";
 
 pub const ALLOWED: [&str; 1] = [LINT_NAME];
-const LINT_NAME: &str = "bool_comparison";
+pub(crate) const LINT_NAME: &str = "bool_comparison";
 
 /// Generates the fixed boolean for a boolean comparison. It will transform `x == false` to `!x`
 pub fn generate_fixed_text_for_comparison(
@@ -26,19 +27,25 @@ pub fn generate_fixed_text_for_comparison(
     let op_kind = node.op(db).as_syntax_node().kind(db);
     let lhs = lhs.trim();
     let rhs = rhs.trim();
+    let negated_lhs = || negate_expr(db, &node.lhs(db));
+    let negated_rhs = || negate_expr(db, &node.rhs(db));
 
     match (lhs, rhs, op_kind) {
         // lhs
-        ("false", _, SyntaxKind::TerminalEqEq | SyntaxKind::TokenEqEq) => format!("!{} ", rhs),
+        ("false", _, SyntaxKind::TerminalEqEq | SyntaxKind::TokenEqEq) => {
+            format!("{} ", negated_rhs())
+        }
         ("true", _, SyntaxKind::TerminalEqEq | SyntaxKind::TokenEqEq) => format!("{} ", rhs),
-        ("false", _, SyntaxKind::TerminalNeq) => format!("!{} ", rhs),
-        ("true", _, SyntaxKind::TerminalNeq) => format!("!{} ", rhs),
+        ("false", _, SyntaxKind::TerminalNeq) => format!("{} ", negated_rhs()),
+        ("true", _, SyntaxKind::TerminalNeq) => format!("{} ", negated_rhs()),
 
         // rhs
-        (_, "false", SyntaxKind::TerminalEqEq | SyntaxKind::TokenEqEq) => format!("!{} ", lhs),
+        (_, "false", SyntaxKind::TerminalEqEq | SyntaxKind::TokenEqEq) => {
+            format!("{} ", negated_lhs())
+        }
         (_, "true", SyntaxKind::TerminalEqEq | SyntaxKind::TokenEqEq) => format!("{} ", lhs),
-        (_, "false", SyntaxKind::TerminalNeq) => format!("!{} ", lhs),
-        (_, "true", SyntaxKind::TerminalNeq) => format!("!{} ", lhs),
+        (_, "false", SyntaxKind::TerminalNeq) => format!("{} ", negated_lhs()),
+        (_, "true", SyntaxKind::TerminalNeq) => format!("{} ", negated_lhs()),
 
         _ => node.as_syntax_node().get_text(db).to_string(),
     }
@@ -51,14 +58,6 @@ pub fn check_bool_comparison(
     arenas: &Arenas,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    // Checks if the lint is allowed in an upper scope
-    let mut current_node = expr_func.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
     // Check if the function call is the bool partial eq function (==).
     if !expr_func
         .function
@@ -0,0 +1,92 @@
+use cairo_lang_defs::ids::TopLevelLanguageElementId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{
+    Arenas, Condition, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, ExprIf,
+};
+use cairo_lang_syntax::node::ast;
+use cairo_lang_syntax::node::TypedStablePtr;
+use if_chain::if_chain;
+
+use super::{function_trait_name_from_fn_id, BOOL_NOT, EQ, FALSE, GE, GT, LE, LT, NE, TRUE};
+
+pub const NEEDLESS_BOOL: &str = "Unnecessary `if` returning a boolean literal in each branch. \
+                                 Consider using the condition directly.";
+pub const NONMINIMAL_BOOL: &str = "This boolean expression can be simplified. Consider removing \
+                                   the negation.";
+
+pub const ALLOWED: [&str; 2] = [needless_bool::LINT_NAME, nonminimal_bool::LINT_NAME];
+
+pub(crate) mod needless_bool {
+    pub(crate) const LINT_NAME: &str = "needless_bool";
+}
+pub(crate) mod nonminimal_bool {
+    pub(crate) const LINT_NAME: &str = "nonminimal_bool";
+}
+
+/// Checks for `if c { true } else { false }` and `if c { false } else { true }`.
+pub fn check_needless_bool(
+    db: &dyn SemanticGroup,
+    expr_if: &ExprIf,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if_chain! {
+        if let Condition::BoolExpr(_) = expr_if.condition;
+        if let Some(else_block) = expr_if.else_block;
+        if let Some(if_value) = block_bool_value(db, expr_if.if_block, arenas);
+        if let Some(else_value) = block_bool_value(db, else_block, arenas);
+        if if_value != else_value;
+        then {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: expr_if.stable_ptr.untyped(),
+                message: NEEDLESS_BOOL.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Checks for negated comparisons (`!(a == b)`) and double negations (`!!x`).
+pub fn check_nonminimal_bool(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if_chain! {
+        if function_trait_name_from_fn_id(db, &expr_func.function) == BOOL_NOT;
+        // Only the `!` operator is simplified, not explicit calls to `Not::not`.
+        if let ast::Expr::Unary(_) = expr_func.stable_ptr.lookup(db.upcast());
+        if let [ExprFunctionCallArg::Value(operand)] = expr_func.args.as_slice();
+        if let Expr::FunctionCall(operand_func) = &arenas.exprs[*operand];
+        if [BOOL_NOT, EQ, NE, LT, LE, GT, GE]
+            .contains(&function_trait_name_from_fn_id(db, &operand_func.function).as_str());
+        then {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: expr_func.stable_ptr.untyped(),
+                message: NONMINIMAL_BOOL.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Gets the value of a block only made of a `true` or `false` tail expression.
+fn block_bool_value(db: &dyn SemanticGroup, expr_id: ExprId, arenas: &Arenas) -> Option<bool> {
+    let Expr::Block(expr_block) = &arenas.exprs[expr_id] else {
+        return None;
+    };
+    if !expr_block.statements.is_empty() {
+        return None;
+    }
+    let Expr::EnumVariantCtor(enum_variant) = &arenas.exprs[expr_block.tail?] else {
+        return None;
+    };
+    match enum_variant.variant.id.full_path(db.upcast()).as_str() {
+        TRUE => Some(true),
+        FALSE => Some(false),
+        _ => None,
+    }
+}
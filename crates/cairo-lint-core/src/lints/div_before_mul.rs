@@ -0,0 +1,92 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{
+    Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, ExprVar, Pattern, Statement, VarId,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+use super::{function_trait_name_from_fn_id, DIV, MUL};
+
+pub const DIV_BEFORE_MUL: &str = "Division before multiplication truncates the intermediate \
+                                  result and loses precision. Consider multiplying first.";
+
+pub const ALLOWED: [&str; 1] = [LINT_NAME];
+pub(crate) const LINT_NAME: &str = "div_before_mul";
+
+/// Checks for an integer multiplication of the result of an integer division, either directly
+/// (`(a / b) * c`) or through a variable defined in the same block.
+pub fn check_div_before_mul(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if function_trait_name_from_fn_id(db, &expr_func.function) != MUL || !is_integer(db, expr_func)
+    {
+        return;
+    }
+    let mul_node = expr_func.stable_ptr.lookup(db.upcast()).as_syntax_node();
+    let has_div_operand = expr_func.args.iter().any(|arg| match arg {
+        ExprFunctionCallArg::Value(expr_id) => is_div(db, &mul_node, *expr_id, arenas),
+        ExprFunctionCallArg::Reference(_) => false,
+    });
+    if has_div_operand {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: expr_func.stable_ptr.untyped(),
+            message: DIV_BEFORE_MUL.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Checks if the operand of the multiplication is an integer division, or an immutable variable
+/// defined by one in the block of the multiplication.
+fn is_div(db: &dyn SemanticGroup, mul_node: &SyntaxNode, expr_id: ExprId, arenas: &Arenas) -> bool {
+    match &arenas.exprs[expr_id] {
+        Expr::FunctionCall(expr_func) => {
+            function_trait_name_from_fn_id(db, &expr_func.function) == DIV
+                && is_integer(db, expr_func)
+        }
+        Expr::Var(ExprVar {
+            var: VarId::Local(local_var),
+            ..
+        }) => arenas.statements.iter().any(|(_id, statement)| {
+            let Statement::Let(statement_let) = statement else {
+                return false;
+            };
+            let Pattern::Variable(pattern) = &arenas.patterns[statement_let.pattern] else {
+                return false;
+            };
+            pattern.var.id == *local_var
+                && !pattern.var.is_mut
+                && is_div(db, mul_node, statement_let.expr, arenas)
+                && enclosing_statement_list(db.upcast(), mul_node)
+                    == statement_let
+                        .stable_ptr
+                        .lookup(db.upcast())
+                        .as_syntax_node()
+                        .parent()
+        }),
+        _ => false,
+    }
+}
+
+/// Checks if the operation is done on integers, where the division is truncated.
+fn is_integer(db: &dyn SemanticGroup, expr_func: &ExprFunctionCall) -> bool {
+    expr_func.ty.format(db).starts_with("core::integer::")
+}
+
+/// Gets the statement list of the innermost block containing the node.
+fn enclosing_statement_list(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Option<SyntaxNode> {
+    let mut current_node = node.parent();
+    while let Some(node) = current_node {
+        if node.kind(db) == SyntaxKind::StatementList {
+            return Some(node);
+        }
+        current_node = node.parent();
+    }
+    None
+}
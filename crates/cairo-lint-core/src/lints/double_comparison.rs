@@ -8,7 +8,6 @@ use cairo_lang_semantic::{
 };
 use cairo_lang_syntax::node::ast::{BinaryOperator, Expr as AstExpr};
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 
 use super::function_trait_name_from_fn_id;
@@ -28,17 +27,17 @@ pub const ALLOWED: [&str; 4] = [
     impossible_comparison::LINT_NAME,
 ];
 
-mod redundant_comaprison {
-    pub(super) const LINT_NAME: &str = "redundant_comparison";
+pub(crate) mod redundant_comaprison {
+    pub(crate) const LINT_NAME: &str = "redundant_comparison";
 }
-mod contradictory_comparison {
-    pub(super) const LINT_NAME: &str = "contradictory_comparison";
+pub(crate) mod contradictory_comparison {
+    pub(crate) const LINT_NAME: &str = "contradictory_comparison";
 }
-mod simplifiable_comparison {
-    pub(super) const LINT_NAME: &str = "simplifiable_comparison";
+pub(crate) mod simplifiable_comparison {
+    pub(crate) const LINT_NAME: &str = "simplifiable_comparison";
 }
-mod impossible_comparison {
-    pub(super) const LINT_NAME: &str = "impossible_comparison";
+pub(crate) mod impossible_comparison {
+    pub(crate) const LINT_NAME: &str = "impossible_comparison";
 }
 
 pub fn check_double_comparison(
@@ -47,27 +46,6 @@ pub fn check_double_comparison(
     arenas: &Arenas,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    let (
-        mut ignore_redundant,
-        mut ignore_contradictory,
-        mut ignore_simplifiable,
-        mut ignore_impossible,
-    ) = (false, false, false, false);
-    // Checks if the lint is allowed in an upper scope.
-    let mut current_node = expr_logical.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    let syntax_db = db.upcast();
-    while let Some(node) = current_node.parent() {
-        ignore_redundant |=
-            node.has_attr_with_arg(syntax_db, "allow", redundant_comaprison::LINT_NAME);
-        ignore_contradictory |=
-            node.has_attr_with_arg(syntax_db, "allow", contradictory_comparison::LINT_NAME);
-        ignore_simplifiable |=
-            node.has_attr_with_arg(syntax_db, "allow", simplifiable_comparison::LINT_NAME);
-        ignore_impossible |=
-            node.has_attr_with_arg(syntax_db, "allow", impossible_comparison::LINT_NAME);
-        current_node = node;
-    }
-
     let Expr::FunctionCall(lhs_comparison) = &arenas.exprs[expr_logical.lhs] else {
         return;
     };
@@ -90,17 +68,15 @@ pub fn check_double_comparison(
     );
 
     // Check the impossible comparison
-    if !ignore_impossible
-        && check_impossible_comparison(
-            lhs_comparison,
-            rhs_comparison,
-            &lhs_fn_trait_name,
-            &rhs_fn_trait_name,
-            expr_logical,
-            db,
-            arenas,
-        )
-    {
+    if check_impossible_comparison(
+        lhs_comparison,
+        rhs_comparison,
+        &lhs_fn_trait_name,
+        &rhs_fn_trait_name,
+        expr_logical,
+        db,
+        arenas,
+    ) {
         diagnostics.push(PluginDiagnostic {
             message: IMPOSSIBLE_COMPARISON.to_string(),
             stable_ptr: expr_logical.stable_ptr.untyped(),
@@ -170,33 +146,27 @@ pub fn check_double_comparison(
         return;
     }
 
-    if !ignore_simplifiable
-        && is_simplifiable_double_comparison(
-            &lhs_fn_trait_name,
-            &rhs_fn_trait_name,
-            &expr_logical.op,
-        )
-    {
+    if is_simplifiable_double_comparison(&lhs_fn_trait_name, &rhs_fn_trait_name, &expr_logical.op) {
         diagnostics.push(PluginDiagnostic {
             message: SIMPLIFIABLE_COMPARISON.to_string(),
             stable_ptr: expr_logical.stable_ptr.untyped(),
             severity: Severity::Warning,
         });
-    } else if !ignore_redundant
-        && is_redundant_double_comparison(&lhs_fn_trait_name, &rhs_fn_trait_name, &expr_logical.op)
-    {
+    } else if is_redundant_double_comparison(
+        &lhs_fn_trait_name,
+        &rhs_fn_trait_name,
+        &expr_logical.op,
+    ) {
         diagnostics.push(PluginDiagnostic {
             message: REDUNDANT_COMPARISON.to_string(),
             stable_ptr: expr_logical.stable_ptr.untyped(),
             severity: Severity::Warning,
         });
-    } else if !ignore_contradictory
-        && is_contradictory_double_comparison(
-            &lhs_fn_trait_name,
-            &rhs_fn_trait_name,
-            &expr_logical.op,
-        )
-    {
+    } else if is_contradictory_double_comparison(
+        &lhs_fn_trait_name,
+        &rhs_fn_trait_name,
+        &expr_logical.op,
+    ) {
         diagnostics.push(PluginDiagnostic {
             message: CONTRADICTORY_COMPARISON.to_string(),
             stable_ptr: expr_logical.stable_ptr.untyped(),
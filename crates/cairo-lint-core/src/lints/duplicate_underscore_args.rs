@@ -1,33 +1,56 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::Parameter;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+
+use crate::diagnostics::{AttachedDetails, DiagnosticDetails, SecondaryLabel};
 
 pub const DUPLICATE_UNDERSCORE_ARGS: &str = "duplicate arguments, having another argument having almost the same name \
                                              makes code comprehension and documentation more difficult";
 
 pub const ALLOWED: [&str; 1] = [LINT_NAME];
-const LINT_NAME: &str = "duplicate_underscore_args";
+pub(crate) const LINT_NAME: &str = "duplicate_underscore_args";
 
 /// Checks for functions that have the same argument name but prefix with `_`. For example
 /// `fn foo(a, _a)`
 pub fn check_duplicate_underscore_args(
+    db: &dyn SemanticGroup,
     params: Vec<Parameter>,
     diagnostics: &mut Vec<PluginDiagnostic>,
+    details: &mut Vec<AttachedDetails>,
 ) {
-    let mut registered_names: HashSet<String> = HashSet::new();
+    let mut registered_params: HashMap<String, Parameter> = HashMap::new();
 
     for param in params {
         let param_name = param.name.to_string();
         let stripped_name = param_name.strip_prefix('_').unwrap_or(&param_name);
 
-        if !registered_names.insert(stripped_name.to_string()) {
-            diagnostics.push(PluginDiagnostic {
+        if let Some(registered_param) = registered_params.get(stripped_name) {
+            let diagnostic = PluginDiagnostic {
                 stable_ptr: param.stable_ptr.0,
                 message: DUPLICATE_UNDERSCORE_ARGS.to_string(),
                 severity: Severity::Warning,
-            });
+            };
+            details.push(AttachedDetails::new(
+                &diagnostic,
+                DiagnosticDetails {
+                    labels: vec![SecondaryLabel {
+                        span: registered_param
+                            .stable_ptr
+                            .lookup(db.upcast())
+                            .as_syntax_node()
+                            .span_without_trivia(db.upcast()),
+                        label: format!("`{}` is already declared here", registered_param.name),
+                    }],
+                    notes: vec![],
+                },
+            ));
+            diagnostics.push(diagnostic);
+        } else {
+            registered_params.insert(stripped_name.to_string(), param.clone());
         }
     }
 }
@@ -3,46 +3,38 @@ use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 
 use super::{function_trait_name_from_fn_id, AND, DIV, EQ, GE, GT, LE, LT, NE, NOT, OR, SUB, XOR};
+use crate::diagnostics::{AttachedDetails, DiagnosticDetails, SecondaryLabel};
 
-const DIV_EQ_OP: &str =
+pub const DIV_EQ_OP: &str =
     "Division with identical operands, this operation always results in one (except for zero) and \
                          may indicate a logic error";
-const EQ_COMP_OP: &str =
+pub const EQ_COMP_OP: &str =
     "Comparison with identical operands, this operation always results in true and may indicate a logic error";
-const NEQ_COMP_OP: &str =
+pub const NEQ_COMP_OP: &str =
     "Comparison with identical operands, this operation always results in false and may indicate a logic error";
-const EQ_DIFF_OP: &str =
+pub const EQ_DIFF_OP: &str =
     "Subtraction with identical operands, this operation always results in zero and may indicate a logic error";
-const EQ_BITWISE_OP: &str =
+pub const EQ_BITWISE_OP: &str =
     "Bitwise operation with identical operands, this operation always results in the same \
                              value and may indicate a logic error";
-const EQ_LOGICAL_OP: &str =
+pub const EQ_LOGICAL_OP: &str =
     "Logical operation with identical operands, this operation always results in the same \
                              value and may indicate a logic error";
 
 pub const ALLOWED: [&str; 1] = [LINT_NAME];
-const LINT_NAME: &str = "eq_op";
+pub(crate) const LINT_NAME: &str = "eq_op";
 
 pub fn check_eq_op(
     db: &dyn SemanticGroup,
     expr_func: &ExprFunctionCall,
     arenas: &Arenas,
     diagnostics: &mut Vec<PluginDiagnostic>,
+    details: &mut Vec<AttachedDetails>,
 ) {
-    // Checks if the lint is allowed in any upper scope
-    let mut current_node = expr_func.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
-
     // We're looking for binary operations
     if expr_func.args.len() != 2 {
         return;
@@ -97,13 +89,18 @@ pub fn check_eq_op(
 
     let op = function_trait_name_from_fn_id(db, &expr_func.function);
 
-    if are_operands_equal(db.upcast(), lhs, rhs) {
+    if are_operands_equal(db.upcast(), lhs.clone(), rhs.clone()) {
         if let Some(message) = get_diagnostic_message(&op) {
-            diagnostics.push(PluginDiagnostic {
+            let diagnostic = PluginDiagnostic {
                 stable_ptr: expr_func.stable_ptr.untyped(),
                 message: message.to_owned(),
                 severity: Severity::Warning,
-            });
+            };
+            details.push(AttachedDetails::new(
+                &diagnostic,
+                operands_details(db.upcast(), lhs, rhs),
+            ));
+            diagnostics.push(diagnostic);
         }
     }
 }
@@ -123,3 +120,23 @@ fn get_diagnostic_message(op: &str) -> Option<&'static str> {
         _ => None,
     }
 }
+
+/// Points at both operands of the reported operation.
+fn operands_details(db: &dyn SyntaxGroup, lhs: SyntaxNode, rhs: SyntaxNode) -> DiagnosticDetails {
+    DiagnosticDetails {
+        labels: vec![
+            SecondaryLabel {
+                span: lhs.span_without_trivia(db),
+                label: "left operand".to_string(),
+            },
+            SecondaryLabel {
+                span: rhs.span_without_trivia(db),
+                label: "right operand, identical to the left one".to_string(),
+            },
+        ],
+        notes: vec![format!(
+            "both operands are `{}`",
+            lhs.get_text_without_trivia(db)
+        )],
+    }
+}
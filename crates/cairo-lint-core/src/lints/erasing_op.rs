@@ -2,8 +2,7 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
-use cairo_lang_syntax::node::helpers::QueryAttrs;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::TypedStablePtr;
 use num_bigint::BigInt;
 
 use super::{function_trait_name_from_fn_id, AND};
@@ -14,7 +13,7 @@ pub const ERASING_OPERATION: &str =
                                      Consider replacing the entire expression with 0.";
 
 pub const ALLOWED: [&str; 1] = [LINT_NAME];
-const LINT_NAME: &str = "erasing_op";
+pub(crate) const LINT_NAME: &str = "erasing_op";
 
 pub fn check_erasing_operation(
     db: &dyn SemanticGroup,
@@ -22,14 +21,6 @@ pub fn check_erasing_operation(
     arenas: &Arenas,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    // Checks if the lint is allowed in any upper scope
-    let mut current_node = expr_func.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
     let func = function_trait_name_from_fn_id(db, &expr_func.function);
 
     let is_erasing_operation = match func.as_str() {
@@ -45,7 +36,7 @@ pub fn check_erasing_operation(
         });
     }
 }
-fn is_zero(arg: &ExprFunctionCallArg, arenas: &Arenas) -> bool {
+pub(crate) fn is_zero(arg: &ExprFunctionCallArg, arenas: &Arenas) -> bool {
     match arg {
         ExprFunctionCallArg::Value(expr) => match &arenas.exprs[*expr] {
             Expr::Literal(val) => val.value == BigInt::ZERO,
@@ -0,0 +1,155 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::corelib::core_felt252_ty;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{
+    Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, ExprVar, Pattern, Statement,
+    TypeId, VarId,
+};
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use super::{function_trait_name_from_fn_id, DIV, GE, GT, LE, LT, SUB, TRY_INTO};
+
+pub const FELT252_COMPARISON: &str =
+    "Ordering comparison between `felt252` values. Field elements wrap around the prime, \
+     consider converting them to an integer type first.";
+pub const FELT252_DIVISION: &str = "`felt252` division is a field division (a multiplication by \
+                                    the inverse), not an integer division. Consider using an \
+                                    integer type instead.";
+pub const FELT252_SUB_UNDERFLOW: &str =
+    "This `felt252` subtraction wraps around if the result is negative but is used as a bounded \
+     integer. Consider checking the operands or using an integer type.";
+
+pub const ALLOWED: [&str; 3] = [
+    felt252_comparison::LINT_NAME,
+    felt252_division::LINT_NAME,
+    felt252_sub_underflow::LINT_NAME,
+];
+
+pub(crate) mod felt252_comparison {
+    pub(crate) const LINT_NAME: &str = "felt252_comparison";
+}
+pub(crate) mod felt252_division {
+    pub(crate) const LINT_NAME: &str = "felt252_division";
+}
+pub(crate) mod felt252_sub_underflow {
+    pub(crate) const LINT_NAME: &str = "felt252_sub_underflow";
+}
+
+/// Checks for the `felt252` operations that don't behave like their integer counterpart:
+/// - ordering comparisons (`<`, `<=`, `>`, `>=`) between felts
+/// - felt division
+/// - felt subtraction converted to an unsigned integer with `try_into` or compared
+pub fn check_felt252_arithmetic(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    match function_trait_name_from_fn_id(db, &expr_func.function).as_str() {
+        LT | LE | GT | GE => {
+            if has_felt252_operand(db, expr_func, arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: expr_func.stable_ptr.untyped(),
+                    message: FELT252_COMPARISON.to_string(),
+                    severity: Severity::Warning,
+                });
+            }
+            check_felt252_sub_args(db, expr_func, arenas, diagnostics);
+        }
+        DIV => {
+            if has_felt252_operand(db, expr_func, arenas) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: expr_func.stable_ptr.untyped(),
+                    message: FELT252_DIVISION.to_string(),
+                    severity: Severity::Warning,
+                });
+            }
+        }
+        TRY_INTO if is_unsigned_int_option(db, expr_func.ty) => {
+            check_felt252_sub_args(db, expr_func, arenas, diagnostics);
+        }
+        _ => {}
+    }
+}
+
+fn has_felt252_operand(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    arenas: &Arenas,
+) -> bool {
+    expr_func.args.iter().any(|arg| match arg {
+        ExprFunctionCallArg::Value(expr_id) => arenas.exprs[*expr_id].ty() == core_felt252_ty(db),
+        ExprFunctionCallArg::Reference(_) => false,
+    })
+}
+
+/// Checks if the type is an `Option` of an unsigned integer, i.e. the result of `try_into::<u*>`.
+fn is_unsigned_int_option(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    ty.format(db)
+        .starts_with("core::option::Option::<core::integer::u")
+}
+
+/// Reports the felt subtractions passed as arguments of the function call.
+fn check_felt252_sub_args(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    for arg in &expr_func.args {
+        let ExprFunctionCallArg::Value(expr_id) = arg else {
+            continue;
+        };
+        let Some(sub) = felt252_sub(db, *expr_id, arenas) else {
+            continue;
+        };
+        let stable_ptr = sub.stable_ptr.untyped();
+        // A subtraction bound to a variable can be used several times.
+        if !diagnostics
+            .iter()
+            .any(|diag| diag.stable_ptr == stable_ptr && diag.message == FELT252_SUB_UNDERFLOW)
+        {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr,
+                message: FELT252_SUB_UNDERFLOW.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Gets the felt subtraction computing the expression, either directly or through an immutable
+/// variable.
+fn felt252_sub<'a>(
+    db: &dyn SemanticGroup,
+    expr_id: ExprId,
+    arenas: &'a Arenas,
+) -> Option<&'a ExprFunctionCall> {
+    match &arenas.exprs[expr_id] {
+        Expr::FunctionCall(expr_func)
+            if expr_func.ty == core_felt252_ty(db)
+                && function_trait_name_from_fn_id(db, &expr_func.function) == SUB =>
+        {
+            Some(expr_func)
+        }
+        Expr::Var(ExprVar {
+            var: VarId::Local(local_var),
+            ..
+        }) => arenas
+            .statements
+            .iter()
+            .find_map(|(_id, statement)| match statement {
+                Statement::Let(statement_let) => match &arenas.patterns[statement_let.pattern] {
+                    Pattern::Variable(pattern)
+                        if pattern.var.id == *local_var && !pattern.var.is_mut =>
+                    {
+                        felt252_sub(db, statement_let.expr, arenas)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }),
+        _ => None,
+    }
+}
@@ -0,0 +1,102 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
+use cairo_lang_syntax::node::ast::{ArgClause, BinaryOperator, Expr as AstExpr, ExprBinary};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::TypedStablePtr;
+use num_bigint::BigInt;
+
+use super::erasing_op::is_zero;
+use super::{function_trait_name_from_fn_id, ADD, DIV, MUL, OR, SUB, XOR};
+
+pub const IDENTITY_OP: &str = "This operation has no effect (e.g., addition of 0). Consider \
+                               replacing the entire expression with the other operand.";
+
+pub const ALLOWED: [&str; 1] = [LINT_NAME];
+pub(crate) const LINT_NAME: &str = "identity_op";
+
+/// Checks for operations that return one of their operands unchanged, e.g. `x + 0` or `x * 1`.
+pub fn check_identity_op(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let func = function_trait_name_from_fn_id(db, &expr_func.function);
+
+    let is_identity_op = match func.as_str() {
+        ADD | OR | XOR => {
+            is_zero(&expr_func.args[0], arenas) || is_zero(&expr_func.args[1], arenas)
+        }
+        SUB => is_zero(&expr_func.args[1], arenas),
+        MUL => is_one(&expr_func.args[0], arenas) || is_one(&expr_func.args[1], arenas),
+        DIV => is_one(&expr_func.args[1], arenas),
+        _ => false,
+    };
+    if is_identity_op {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: expr_func.stable_ptr.untyped(),
+            message: IDENTITY_OP.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+fn is_one(arg: &ExprFunctionCallArg, arenas: &Arenas) -> bool {
+    match arg {
+        ExprFunctionCallArg::Value(expr) => match &arenas.exprs[*expr] {
+            Expr::Literal(val) => val.value == BigInt::from(1),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Gets the operand of an identity operation that is kept, i.e. the one that isn't the identity
+/// element of the operator, for an operator (e.g. `0 + x`) or a method call (e.g. `0.add(x)`).
+pub fn kept_operand(db: &dyn SyntaxGroup, expr: &ExprBinary) -> Option<AstExpr> {
+    let is_identity_element = |operand: &AstExpr, value: u8| {
+        matches!(operand, AstExpr::Literal(literal)
+            if literal.numeric_value(db) == Some(BigInt::from(value)))
+    };
+    let (rhs, lhs_identity_element) = match expr.op(db) {
+        BinaryOperator::Plus(_) | BinaryOperator::Or(_) | BinaryOperator::Xor(_) => {
+            (expr.rhs(db), Some(0))
+        }
+        BinaryOperator::Mul(_) => (expr.rhs(db), Some(1)),
+        BinaryOperator::Dot(_) => {
+            let AstExpr::FunctionCall(method_call) = expr.rhs(db) else {
+                return None;
+            };
+            let [arg] = method_call
+                .arguments(db)
+                .arguments(db)
+                .elements(db)
+                .try_into()
+                .ok()?;
+            let ArgClause::Unnamed(arg_clause) = arg.arg_clause(db) else {
+                return None;
+            };
+            let method = method_call
+                .path(db)
+                .as_syntax_node()
+                .get_text_without_trivia(db);
+            let lhs_identity_element = match method.as_str() {
+                "add" | "bitor" | "bitxor" => Some(0),
+                "mul" => Some(1),
+                _ => None,
+            };
+            (arg_clause.value(db), lhs_identity_element)
+        }
+        _ => (expr.rhs(db), None),
+    };
+    let lhs = expr.lhs(db);
+    Some(
+        if lhs_identity_element.is_some_and(|value| is_identity_element(&lhs, value)) {
+            rhs
+        } else {
+            lhs
+        },
+    )
+}
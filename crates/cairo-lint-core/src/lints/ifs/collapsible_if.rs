@@ -2,13 +2,12 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprIf, Statement};
-use cairo_lang_syntax::node::helpers::QueryAttrs;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::TypedStablePtr;
 use if_chain::if_chain;
 
 pub const COLLAPSIBLE_IF: &str =
     "Each `if`-statement adds one level of nesting, which makes code look more complex than it really is.";
-pub(super) const LINT_NAME: &str = "collapsible_if";
+pub(crate) const LINT_NAME: &str = "collapsible_if";
 
 /// Checks for
 /// ```ignore
@@ -25,19 +24,11 @@ pub(super) const LINT_NAME: &str = "collapsible_if";
 /// }
 /// ```
 pub fn check_collapsible_if(
-    db: &dyn SemanticGroup,
+    _db: &dyn SemanticGroup,
     expr_if: &ExprIf,
     arenas: &Arenas,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    // Checks if the lint is allowed in any upper scope
-    let mut current_node = expr_if.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
     let Expr::Block(ref if_block) = arenas.exprs[expr_if.if_block] else {
         return;
     };
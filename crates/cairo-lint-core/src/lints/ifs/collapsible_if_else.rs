@@ -2,12 +2,11 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprBlock, ExprIf, Statement};
-use cairo_lang_syntax::node::helpers::QueryAttrs;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::TypedStablePtr;
 use if_chain::if_chain;
 
 pub const COLLAPSIBLE_IF_ELSE: &str = "Consider using else if instead of else { if ... }";
-pub(super) const LINT_NAME: &str = "collapsible_if_else";
+pub(crate) const LINT_NAME: &str = "collapsible_if_else";
 
 /// Checks for
 /// ```ignore
@@ -28,18 +27,11 @@ pub(super) const LINT_NAME: &str = "collapsible_if_else";
 /// }
 /// ```
 pub fn check_collapsible_if_else(
-    db: &dyn SemanticGroup,
+    _db: &dyn SemanticGroup,
     expr_if: &ExprIf,
     arenas: &Arenas,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    let mut current_node = expr_if.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
     // Extract the expression from the ElseClause
     let Some(else_block) = expr_if.else_block else {
         return;
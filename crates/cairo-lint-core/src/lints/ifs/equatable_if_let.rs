@@ -2,12 +2,11 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Condition, Expr, ExprIf, Pattern, PatternId};
-use cairo_lang_syntax::node::helpers::QueryAttrs;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::TypedStablePtr;
 
 pub const EQUATABLE_IF_LET: &str =
     "`if let` pattern used for equatable value. Consider using a simple comparison `==` instead";
-pub(super) const LINT_NAME: &str = "equatable_if_let";
+pub(crate) const LINT_NAME: &str = "equatable_if_let";
 
 /// Checks for
 /// ```ignore
@@ -22,19 +21,11 @@ pub(super) const LINT_NAME: &str = "equatable_if_let";
 /// }
 /// ````
 pub fn check_equatable_if_let(
-    db: &dyn SemanticGroup,
+    _db: &dyn SemanticGroup,
     expr: &ExprIf,
     arenas: &Arenas,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    let mut current_node = expr.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
-
     if let Condition::Let(condition_let, patterns) = &expr.condition {
         // Simple literals and variables
         let expr_is_simple = matches!(
@@ -2,30 +2,26 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Condition, Expr, ExprIf};
-use cairo_lang_syntax::node::helpers::QueryAttrs;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 
 use super::ensure_no_ref_arg;
+use crate::diagnostics::{AttachedDetails, DiagnosticDetails, SecondaryLabel};
 
 pub const DUPLICATE_IF_CONDITION: &str = "Consecutive `if` with the same condition found.";
 
-pub(super) const LINT_NAME: &str = "ifs_same_cond";
+pub(crate) const LINT_NAME: &str = "ifs_same_cond";
 
+/// Checks for `else if` repeating the condition of the `if` before them, including the patterns of
+/// `if let` conditions, as their body can't be reached.
 pub fn check_duplicate_if_condition(
     db: &dyn SemanticGroup,
     expr_if: &ExprIf,
     arenas: &Arenas,
     diagnostics: &mut Vec<PluginDiagnostic>,
+    details: &mut Vec<AttachedDetails>,
 ) {
-    // Checks if the lint is allowed in any upper scope
-    let mut current_node = expr_if.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
     let cond_expr = match &expr_if.condition {
         Condition::BoolExpr(expr_id) => &arenas.exprs[*expr_id],
         Condition::Let(expr_id, _patterns) => &arenas.exprs[*expr_id],
@@ -40,11 +36,10 @@ pub fn check_duplicate_if_condition(
     }
 
     let mut current_block = expr_if.else_block;
-    let if_condition_text = cond_expr
-        .stable_ptr()
-        .lookup(db.upcast())
-        .as_syntax_node()
-        .get_text_without_trivia(db.upcast());
+    let Some(if_condition) = condition_node(db.upcast(), expr_if) else {
+        return;
+    };
+    let if_condition_text = if_condition.get_text_without_trivia(db.upcast());
 
     while let Some(expr_id) = current_block {
         if let Expr::If(else_if_block) = &arenas.exprs[expr_id] {
@@ -62,18 +57,37 @@ pub fn check_duplicate_if_condition(
                 }
             }
 
-            let else_if_condition_text = else_if_cond
-                .stable_ptr()
-                .lookup(db.upcast())
-                .as_syntax_node()
-                .get_text_without_trivia(db.upcast());
+            let Some(else_if_condition) = condition_node(db.upcast(), else_if_block) else {
+                continue;
+            };
 
-            if if_condition_text == else_if_condition_text {
-                diagnostics.push(PluginDiagnostic {
+            if if_condition_text == else_if_condition.get_text_without_trivia(db.upcast()) {
+                let diagnostic = PluginDiagnostic {
                     stable_ptr: expr_if.stable_ptr.untyped(),
                     message: DUPLICATE_IF_CONDITION.to_string(),
                     severity: Severity::Warning,
-                });
+                };
+                details.push(AttachedDetails::new(
+                    &diagnostic,
+                    DiagnosticDetails {
+                        labels: vec![
+                            SecondaryLabel {
+                                span: if_condition.span_without_trivia(db.upcast()),
+                                label: "condition first checked here".to_string(),
+                            },
+                            SecondaryLabel {
+                                span: else_if_condition.span_without_trivia(db.upcast()),
+                                label: "same condition checked again here".to_string(),
+                            },
+                        ],
+                        notes: vec![
+                            "the second branch can only run when the condition is false, so its \
+                             body is unreachable"
+                                .to_string(),
+                        ],
+                    },
+                ));
+                diagnostics.push(diagnostic);
                 break;
             }
         } else {
@@ -81,3 +95,11 @@ pub fn check_duplicate_if_condition(
         }
     }
 }
+
+/// Gets the condition of the `if`, with the patterns of `if let` conditions.
+fn condition_node(db: &dyn SyntaxGroup, expr_if: &ExprIf) -> Option<SyntaxNode> {
+    match expr_if.stable_ptr.lookup(db) {
+        ast::Expr::If(expr_if) => Some(expr_if.condition(db).as_syntax_node()),
+        _ => None,
+    }
+}
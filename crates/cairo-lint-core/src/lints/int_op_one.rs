@@ -2,8 +2,7 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg};
-use cairo_lang_syntax::node::helpers::QueryAttrs;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::TypedStablePtr;
 use if_chain::if_chain;
 
 pub const INT_GE_PLUS_ONE: &str =
@@ -16,7 +15,7 @@ pub const INT_LE_MIN_ONE: &str =
     "Unnecessary sub operation in integer <= comparison. Use simplified comparison.";
 
 pub const ALLOWED: [&str; 1] = [LINT_NAME];
-const LINT_NAME: &str = "int_op_one";
+pub(crate) const LINT_NAME: &str = "int_op_one";
 
 pub fn check_int_op_one(
     db: &dyn SemanticGroup,
@@ -24,15 +23,6 @@ pub fn check_int_op_one(
     arenas: &Arenas,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    // Checks if the lint is allowed in an upper scope
-    let mut current_node = expr_func.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
-
     // Check if the function call is the bool greater or equal (>=) or lower or equal (<=).
     let full_name = expr_func.function.full_name(db);
     if !full_name.contains("core::integer::")
@@ -2,8 +2,7 @@ use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Expr, ExprId, ExprLoop, Statement};
-use cairo_lang_syntax::node::helpers::QueryAttrs;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_syntax::node::TypedStablePtr;
 use if_chain::if_chain;
 
 pub const LOOP_FOR_WHILE: &str =
@@ -11,7 +10,7 @@ pub const LOOP_FOR_WHILE: &str =
                                   loop for clarity and conciseness";
 
 pub const ALLOWED: [&str; 1] = [LINT_NAME];
-pub(super) const LINT_NAME: &str = "loop_for_while";
+pub(crate) const LINT_NAME: &str = "loop_for_while";
 
 /// Checks for
 /// ```ignore
@@ -29,20 +28,11 @@ pub(super) const LINT_NAME: &str = "loop_for_while";
 /// }
 /// ```
 pub fn check_loop_for_while(
-    db: &dyn SemanticGroup,
+    _db: &dyn SemanticGroup,
     loop_expr: &ExprLoop,
     arenas: &Arenas,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    // Check if the lint is allowed in an upper scope
-    let mut current_node = loop_expr.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
-
     // Get the else block  expression
     let Expr::Block(block_expr) = &arenas.exprs[loop_expr.body] else {
         return;
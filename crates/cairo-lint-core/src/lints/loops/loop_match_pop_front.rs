@@ -5,7 +5,6 @@ use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{
     Arenas, Expr, ExprBlock, ExprId, ExprLoop, ExprMatch, Pattern, PatternEnumVariant, Statement,
 };
-use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 
@@ -17,7 +16,7 @@ pub const LOOP_MATCH_POP_FRONT: &str =
 const SPAN_MATCH_POP_FRONT: &str = "\"SpanImpl::pop_front\"";
 
 pub const ALLOWED: [&str; 1] = [LINT_NAME];
-pub(super) const LINT_NAME: &str = "loop_match_pop_front";
+pub(crate) const LINT_NAME: &str = "loop_match_pop_front";
 
 /// Checks for
 /// ```ignore
@@ -42,15 +41,6 @@ pub fn check_loop_match_pop_front(
     diagnostics: &mut Vec<PluginDiagnostic>,
     arenas: &Arenas,
 ) {
-    // Checks if the lint is allowed in an upper scope
-    let mut current_node = loop_expr.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
-
     // Checks that the loop doesn't return anything
     if !loop_expr.ty.is_unit(db) {
         return;
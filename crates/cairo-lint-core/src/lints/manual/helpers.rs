@@ -1,13 +1,27 @@
-use cairo_lang_defs::ids::TopLevelLanguageElementId;
+use cairo_lang_defs::ids::{LanguageElementId, TopLevelLanguageElementId};
+use cairo_lang_semantic::corelib::never_ty;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{
-    Arenas, Condition, Expr, ExprIf, FixedSizeArrayItems, Pattern, Statement, VarId,
+    Arenas, Condition, Expr, ExprFunctionCallArg, ExprId, ExprIf, FixedSizeArrayItems, Pattern,
+    Statement, VarId,
 };
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 use num_bigint::BigInt;
 
 use super::is_expected_variant;
-use crate::lints::{function_trait_name_from_fn_id, ARRAY_NEW, DEFAULT, FALSE};
+use crate::analysis::{contains, location};
+use crate::lints::{
+    function_trait_name_from_fn_id, ADD, AND, ARRAY_NEW, BOOL_NOT, DEFAULT, DIV, EQ, FALSE, GE, GT,
+    LE, LT, MUL, NE, NONE, NOT, OR, SOME, SUB, XOR,
+};
+
+/// Operators that can't panic, their implementations in the corelib don't have any side effect.
+const PURE_OPERATORS: [&str; 11] = [EQ, NE, LT, LE, GT, GE, AND, OR, XOR, NOT, BOOL_NOT];
+/// Arithmetic operators, which can panic (e.g. on overflow). Evaluating them eagerly could panic
+/// where the original code doesn't, so they're only considered side effect free on literals.
+const ARITHMETIC_OPERATORS: [&str; 4] = [ADD, SUB, MUL, DIV];
 
 /// Checks if the input statement is a `FunctionCall` then checks if the function name is the
 /// expected function name
@@ -274,3 +288,297 @@ pub fn check_is_default(db: &dyn SemanticGroup, expr: &Expr, arenas: &Arenas) ->
         _ => false,
     }
 }
+
+/// Checks if the input `Expr` only reads values (literals, variables, constants, members...),
+/// possibly through the operators of the corelib, and can therefore be evaluated eagerly.
+///
+/// # Arguments
+/// * `db` - Reference to the `SemanticGroup` for semantic analysis.
+/// * `expr` - The target expr.
+/// * `arenas` - Reference to the `Arenas` of the function containing the expression.
+///
+/// # Returns
+/// * `true` if the expression doesn't call any function other than the operators of the corelib
+///   and doesn't have any side effect otherwise `false`.
+pub fn is_side_effect_free(db: &dyn SemanticGroup, expr: &Expr, arenas: &Arenas) -> bool {
+    let is_expr_side_effect_free =
+        |expr_id: ExprId| is_side_effect_free(db, &arenas.exprs[expr_id], arenas);
+    match expr {
+        Expr::Literal(_) | Expr::StringLiteral(_) | Expr::Var(_) | Expr::Constant(_) => true,
+        Expr::Snapshot(expr_snapshot) => is_expr_side_effect_free(expr_snapshot.inner),
+        Expr::Desnap(expr_desnap) => is_expr_side_effect_free(expr_desnap.inner),
+        Expr::MemberAccess(expr_member) => is_expr_side_effect_free(expr_member.expr),
+        Expr::EnumVariantCtor(enum_variant) => is_expr_side_effect_free(enum_variant.value_expr),
+        Expr::Tuple(expr_tuple) => expr_tuple
+            .items
+            .iter()
+            .all(|&expr| is_expr_side_effect_free(expr)),
+        Expr::FixedSizeArray(expr_arr) => match &expr_arr.items {
+            FixedSizeArrayItems::ValueAndSize(expr_id, _) => is_expr_side_effect_free(*expr_id),
+            FixedSizeArrayItems::Items(expr_ids) => {
+                expr_ids.iter().all(|&expr| is_expr_side_effect_free(expr))
+            }
+        },
+        Expr::StructCtor(expr_struct) => {
+            expr_struct
+                .members
+                .iter()
+                .all(|&(_, expr)| is_expr_side_effect_free(expr))
+                && expr_struct.base_struct.is_none_or(is_expr_side_effect_free)
+        }
+        // A block that only contains its tail expression
+        Expr::Block(expr_block) => {
+            expr_block.statements.is_empty()
+                && expr_block.tail.is_some_and(is_expr_side_effect_free)
+        }
+        // An operator implemented by the corelib
+        Expr::FunctionCall(expr_func) => {
+            let operator = function_trait_name_from_fn_id(db, &expr_func.function);
+            let operands = expr_func
+                .args
+                .iter()
+                .map(|arg| match arg {
+                    ExprFunctionCallArg::Value(expr_id) => Some(*expr_id),
+                    ExprFunctionCallArg::Reference(_) => None,
+                })
+                .collect::<Option<Vec<_>>>();
+            let Some(operands) = operands else {
+                return false;
+            };
+            let is_pure_operator = PURE_OPERATORS.contains(&operator.as_str())
+                || (ARITHMETIC_OPERATORS.contains(&operator.as_str())
+                    && operands
+                        .iter()
+                        .all(|&operand| is_literal(&arenas.exprs[operand], arenas)));
+            is_pure_operator
+                && expr_func.function.full_name(db).starts_with("core::")
+                && operands.into_iter().all(is_expr_side_effect_free)
+        }
+        _ => false,
+    }
+}
+
+/// Checks if the input `Expr` is a literal, possibly snapshotted.
+fn is_literal(expr: &Expr, arenas: &Arenas) -> bool {
+    match expr {
+        Expr::Literal(_) => true,
+        Expr::Snapshot(expr_snapshot) => is_literal(&arenas.exprs[expr_snapshot.inner], arenas),
+        _ => false,
+    }
+}
+
+/// Checks if the input `Expr` leaves the enclosing function or loop with a `return`, `break`,
+/// `continue` or `?`, which wouldn't be possible anymore inside of a closure.
+pub fn is_exiting_scope(db: &dyn SemanticGroup, expr: &Expr) -> bool {
+    expr.stable_ptr()
+        .lookup(db.upcast())
+        .as_syntax_node()
+        .descendants(db.upcast())
+        .any(|node| {
+            matches!(
+                node.kind(db.upcast()),
+                SyntaxKind::StatementReturn
+                    | SyntaxKind::StatementBreak
+                    | SyntaxKind::StatementContinue
+                    | SyntaxKind::ExprErrorPropagate
+            )
+        })
+}
+
+/// Checks if the input `Expr` is the fallback of a manual `unwrap_or`, i.e. it's not a default
+/// value and it can be evaluated eagerly. A `None` fallback is a manual `flatten` instead.
+pub fn is_unwrap_or_fallback(db: &dyn SemanticGroup, expr_id: ExprId, arenas: &Arenas) -> bool {
+    let expr = &arenas.exprs[expr_id];
+    !check_is_default(db, expr, arenas)
+        && is_side_effect_free(db, expr, arenas)
+        && !is_expected_variant(&peel_block(expr_id, arenas), arenas, db, NONE)
+}
+
+/// Checks if the input `Expr` is the fallback of a manual `unwrap_or_else`, i.e. it's not a
+/// default value, it needs to be evaluated lazily and it can be moved in a closure. The fallbacks
+/// that never return (e.g. a panic) are left to `manual_expect`.
+pub fn is_unwrap_or_else_fallback(
+    db: &dyn SemanticGroup,
+    expr_id: ExprId,
+    arenas: &Arenas,
+) -> bool {
+    let expr = &arenas.exprs[expr_id];
+    !check_is_default(db, expr, arenas)
+        && !is_side_effect_free(db, expr, arenas)
+        && expr.ty() != never_ty(db)
+        && !is_exiting_scope(db, expr)
+        && !mutates_captured_var(db, expr_id, &[], arenas)
+}
+
+/// Checks if the expression assigns a variable declared outside of it or passes it by reference,
+/// which isn't possible anymore once moved in a closure, as the closure captures the variables by
+/// value. The variables of `params` become the parameters of the closure, so they can be modified.
+fn mutates_captured_var(
+    db: &dyn SemanticGroup,
+    expr_id: ExprId,
+    params: &[VarId],
+    arenas: &Arenas,
+) -> bool {
+    let expr_location = location(db, arenas.exprs[expr_id].stable_ptr().untyped());
+    let is_captured = |var: VarId| match var {
+        VarId::Local(local_var) => {
+            !params.contains(&var)
+                && !contains(
+                    expr_location,
+                    location(db, local_var.untyped_stable_ptr(db.upcast())),
+                )
+        }
+        VarId::Param(_) => true,
+        VarId::Item(_) => false,
+    };
+    arenas.exprs.iter().any(|(_expr_id, expr)| {
+        if !contains(expr_location, location(db, expr.stable_ptr().untyped())) {
+            return false;
+        }
+        match expr {
+            Expr::Assignment(assignment) => is_captured(assignment.ref_arg.base_var()),
+            Expr::FunctionCall(expr_func) => expr_func.args.iter().any(|arg| {
+                matches!(arg, ExprFunctionCallArg::Reference(ref_arg)
+                    if is_captured(ref_arg.base_var()))
+            }),
+            _ => false,
+        }
+    })
+}
+
+/// Gets the tail expression of a block only made of its tail expression, or the expression itself
+/// if it's not such a block.
+pub fn peel_block(expr_id: ExprId, arenas: &Arenas) -> ExprId {
+    match &arenas.exprs[expr_id] {
+        Expr::Block(expr_block) if expr_block.statements.is_empty() => expr_block
+            .tail
+            .map_or(expr_id, |tail_expr_id| peel_block(tail_expr_id, arenas)),
+        _ => expr_id,
+    }
+}
+
+/// Checks if the inner pattern of the input `Pattern::Enum` can be used as the parameter of a
+/// closure, i.e. it's a variable or `_`.
+pub fn is_closure_param_pattern(pattern: &Pattern, arenas: &Arenas) -> bool {
+    let Pattern::EnumVariant(enum_var_pattern) = pattern else {
+        return false;
+    };
+    let Some(inner_pattern) = enum_var_pattern.inner_pattern else {
+        return false;
+    };
+    matches!(
+        arenas.patterns[inner_pattern],
+        Pattern::Variable(_) | Pattern::Otherwise(_)
+    )
+}
+
+/// Gets the variable destructured by the inner pattern of the input `Pattern::Enum`, if any, which
+/// becomes the parameter of the closure.
+fn closure_params(pattern: &Pattern, arenas: &Arenas) -> Vec<VarId> {
+    match pattern {
+        Pattern::EnumVariant(enum_var_pattern) => enum_var_pattern
+            .inner_pattern
+            .and_then(|inner_pattern| match &arenas.patterns[inner_pattern] {
+                Pattern::Variable(pattern_var) => Some(VarId::Local(pattern_var.var.id)),
+                _ => None,
+            })
+            .into_iter()
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Checks if the expression wraps a value computed from the destructured variable of the pattern
+/// in the `enum_name` variant, so it can be computed in the closure of `map` or `map_err`.
+///
+/// # Example
+///
+/// ```ignore
+/// match opt {
+///     Option::Some(x) => Option::Some(x + 1),
+///     Option::None => Option::None,
+/// };
+/// ```
+pub fn is_mapped_variant(
+    db: &dyn SemanticGroup,
+    expr_id: ExprId,
+    pattern: &Pattern,
+    arenas: &Arenas,
+    enum_name: &str,
+) -> bool {
+    let expr = &arenas.exprs[peel_block(expr_id, arenas)];
+    let Expr::EnumVariantCtor(enum_expr) = expr else {
+        return false;
+    };
+    enum_expr.variant.id.full_path(db.upcast()) == enum_name
+        && is_closure_param_pattern(pattern, arenas)
+        // Wrapping the destructured variable again doesn't map anything.
+        && !is_destructured_variable_used_and_expected_variant(expr, pattern, db, arenas, enum_name)
+        && !is_exiting_scope(db, &arenas.exprs[enum_expr.value_expr])
+        && !mutates_captured_var(
+            db,
+            enum_expr.value_expr,
+            &closure_params(pattern, arenas),
+            arenas,
+        )
+}
+
+/// Checks if the expression keeps the destructured variable of the pattern only if a condition
+/// holds, so the condition can be the predicate of `filter`.
+///
+/// # Example
+///
+/// ```ignore
+/// match opt {
+///     Option::Some(x) => if x > 0 {
+///         Option::Some(x)
+///     } else {
+///         Option::None
+///     },
+///     Option::None => Option::None,
+/// };
+/// ```
+pub fn is_filter_expr(
+    db: &dyn SemanticGroup,
+    expr_id: ExprId,
+    pattern: &Pattern,
+    arenas: &Arenas,
+) -> bool {
+    if_chain! {
+        if let Expr::If(expr_if) = &arenas.exprs[peel_block(expr_id, arenas)];
+        if let Condition::BoolExpr(condition) = expr_if.condition;
+        if let Some(else_block) = expr_if.else_block;
+        then {
+            return is_destructured_variable_used_and_expected_variant(
+                &arenas.exprs[peel_block(expr_if.if_block, arenas)],
+                pattern,
+                db,
+                arenas,
+                SOME,
+            ) && is_expected_variant(&peel_block(else_block, arenas), arenas, db, NONE)
+                && !is_exiting_scope(db, &arenas.exprs[condition]);
+        }
+    }
+    false
+}
+
+/// Checks if the expression computes a new option (or result) from the destructured variable of
+/// the pattern and can be moved in the closure of `and_then`. The expressions of a manual `map`,
+/// `filter` or `flatten` aren't.
+pub fn is_and_then_expr(
+    db: &dyn SemanticGroup,
+    expr_id: ExprId,
+    pattern: &Pattern,
+    arenas: &Arenas,
+) -> bool {
+    let expr = &arenas.exprs[expr_id];
+    is_closure_param_pattern(pattern, arenas)
+        && !matches!(
+            arenas.exprs[peel_block(expr_id, arenas)],
+            Expr::EnumVariantCtor(_) | Expr::Var(_)
+        )
+        && !is_filter_expr(db, expr_id, pattern, arenas)
+        && expr.ty() != never_ty(db)
+        && !is_exiting_scope(db, expr)
+        && !mutates_captured_var(db, expr_id, &closure_params(pattern, arenas), arenas)
+}
@@ -7,7 +7,7 @@ use cairo_lang_syntax::node::TypedStablePtr;
 use crate::lints::manual::{check_manual, check_manual_if, ManualLint};
 
 pub const MANUAL_ERR: &str = "Manual match for `err` detected. Consider using `err()` instead";
-pub(super) const LINT_NAME: &str = "manual_err";
+pub(crate) const LINT_NAME: &str = "manual_err";
 
 pub fn check_manual_err(
     db: &dyn SemanticGroup,
@@ -15,7 +15,7 @@ pub fn check_manual_err(
     expr_match: &ExprMatch,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    if check_manual(db, expr_match, arenas, ManualLint::ManualErr, LINT_NAME) {
+    if check_manual(db, expr_match, arenas, ManualLint::ManualErr) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_match.stable_ptr.untyped(),
             message: MANUAL_ERR.to_owned(),
@@ -30,7 +30,7 @@ pub fn check_manual_if_err(
     expr_if: &ExprIf,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    if check_manual_if(db, expr_if, arenas, ManualLint::ManualErr, LINT_NAME) {
+    if check_manual_if(db, expr_if, arenas, ManualLint::ManualErr) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_if.stable_ptr.untyped(),
             message: MANUAL_ERR.to_owned(),
@@ -9,7 +9,7 @@ use crate::lints::manual::{check_manual, check_manual_if, ManualLint};
 pub const MANUAL_EXPECT: &str =
     "Manual match for expect detected. Consider using `expect()` instead";
 
-pub(super) const LINT_NAME: &str = "manual_expect";
+pub(crate) const LINT_NAME: &str = "manual_expect";
 
 pub fn check_manual_expect(
     db: &dyn SemanticGroup,
@@ -17,13 +17,7 @@ pub fn check_manual_expect(
     expr_match: &ExprMatch,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    if check_manual(
-        db,
-        expr_match,
-        arenas,
-        ManualLint::ManualOptExpect,
-        LINT_NAME,
-    ) {
+    if check_manual(db, expr_match, arenas, ManualLint::ManualOptExpect) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_match.stable_ptr.untyped(),
             message: MANUAL_EXPECT.to_owned(),
@@ -31,13 +25,7 @@ pub fn check_manual_expect(
         });
     }
 
-    if check_manual(
-        db,
-        expr_match,
-        arenas,
-        ManualLint::ManualResExpect,
-        LINT_NAME,
-    ) {
+    if check_manual(db, expr_match, arenas, ManualLint::ManualResExpect) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_match.stable_ptr.untyped(),
             message: MANUAL_EXPECT.to_owned(),
@@ -52,7 +40,7 @@ pub fn check_manual_if_expect(
     expr_if: &ExprIf,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    if check_manual_if(db, expr_if, arenas, ManualLint::ManualOptExpect, LINT_NAME) {
+    if check_manual_if(db, expr_if, arenas, ManualLint::ManualOptExpect) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_if.stable_ptr.untyped(),
             message: MANUAL_EXPECT.to_owned(),
@@ -60,7 +48,7 @@ pub fn check_manual_if_expect(
         });
     }
 
-    if check_manual_if(db, expr_if, arenas, ManualLint::ManualResExpect, LINT_NAME) {
+    if check_manual_if(db, expr_if, arenas, ManualLint::ManualResExpect) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_if.stable_ptr.untyped(),
             message: MANUAL_EXPECT.to_owned(),
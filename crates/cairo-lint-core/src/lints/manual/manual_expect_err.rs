@@ -8,7 +8,7 @@ use crate::lints::manual::{check_manual, check_manual_if, ManualLint};
 
 pub const MANUAL_EXPECT_ERR: &str =
     "Manual match for `expect_err` detected. Consider using `expect_err()` instead";
-pub(super) const LINT_NAME: &str = "manual_expect_err";
+pub(crate) const LINT_NAME: &str = "manual_expect_err";
 
 pub fn check_manual_expect_err(
     db: &dyn SemanticGroup,
@@ -16,13 +16,7 @@ pub fn check_manual_expect_err(
     expr_match: &ExprMatch,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    if check_manual(
-        db,
-        expr_match,
-        arenas,
-        ManualLint::ManualExpectErr,
-        LINT_NAME,
-    ) {
+    if check_manual(db, expr_match, arenas, ManualLint::ManualExpectErr) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_match.stable_ptr.untyped(),
             message: MANUAL_EXPECT_ERR.to_owned(),
@@ -37,7 +31,7 @@ pub fn check_manual_if_expect_err(
     expr_if: &ExprIf,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    if check_manual_if(db, expr_if, arenas, ManualLint::ManualExpectErr, LINT_NAME) {
+    if check_manual_if(db, expr_if, arenas, ManualLint::ManualExpectErr) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_if.stable_ptr.untyped(),
             message: MANUAL_EXPECT_ERR.to_owned(),
@@ -34,52 +34,28 @@ pub fn check_manual_is(
     expr_match: &ExprMatch,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    if check_manual(
-        db,
-        expr_match,
-        arenas,
-        ManualLint::ManualIsSome,
-        some::LINT_NAME,
-    ) {
+    if check_manual(db, expr_match, arenas, ManualLint::ManualIsSome) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_match.stable_ptr.untyped(),
             message: MANUAL_IS_SOME.to_owned(),
             severity: Severity::Warning,
         });
     }
-    if check_manual(
-        db,
-        expr_match,
-        arenas,
-        ManualLint::ManualIsNone,
-        none::LINT_NAME,
-    ) {
+    if check_manual(db, expr_match, arenas, ManualLint::ManualIsNone) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_match.stable_ptr.untyped(),
             message: MANUAL_IS_NONE.to_owned(),
             severity: Severity::Warning,
         });
     }
-    if check_manual(
-        db,
-        expr_match,
-        arenas,
-        ManualLint::ManualIsOk,
-        ok::LINT_NAME,
-    ) {
+    if check_manual(db, expr_match, arenas, ManualLint::ManualIsOk) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_match.stable_ptr.untyped(),
             message: MANUAL_IS_OK.to_owned(),
             severity: Severity::Warning,
         });
     }
-    if check_manual(
-        db,
-        expr_match,
-        arenas,
-        ManualLint::ManualIsErr,
-        err::LINT_NAME,
-    ) {
+    if check_manual(db, expr_match, arenas, ManualLint::ManualIsErr) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_match.stable_ptr.untyped(),
             message: MANUAL_IS_ERR.to_owned(),
@@ -94,40 +70,28 @@ pub fn check_manual_if_is(
     expr_if: &ExprIf,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    if check_manual_if(
-        db,
-        expr_if,
-        arenas,
-        ManualLint::ManualIsSome,
-        some::LINT_NAME,
-    ) {
+    if check_manual_if(db, expr_if, arenas, ManualLint::ManualIsSome) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_if.stable_ptr.untyped(),
             message: MANUAL_IS_SOME.to_owned(),
             severity: Severity::Warning,
         });
     }
-    if check_manual_if(
-        db,
-        expr_if,
-        arenas,
-        ManualLint::ManualIsNone,
-        none::LINT_NAME,
-    ) {
+    if check_manual_if(db, expr_if, arenas, ManualLint::ManualIsNone) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_if.stable_ptr.untyped(),
             message: MANUAL_IS_NONE.to_owned(),
             severity: Severity::Warning,
         });
     }
-    if check_manual_if(db, expr_if, arenas, ManualLint::ManualIsOk, ok::LINT_NAME) {
+    if check_manual_if(db, expr_if, arenas, ManualLint::ManualIsOk) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_if.stable_ptr.untyped(),
             message: MANUAL_IS_OK.to_owned(),
             severity: Severity::Warning,
         });
     }
-    if check_manual_if(db, expr_if, arenas, ManualLint::ManualIsErr, err::LINT_NAME) {
+    if check_manual_if(db, expr_if, arenas, ManualLint::ManualIsErr) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_if.stable_ptr.untyped(),
             message: MANUAL_IS_ERR.to_owned(),
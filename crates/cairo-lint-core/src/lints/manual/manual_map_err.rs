@@ -0,0 +1,28 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, ExprMatch};
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use crate::lints::manual::{check_manual, ManualLint};
+
+pub const MANUAL_MAP_ERR: &str =
+    "Manual match for `map_err` detected. Consider using `map_err()` instead";
+pub(crate) const LINT_NAME: &str = "manual_map_err";
+
+// There is no `if let` version as the `else` block can't use the content of the other variant.
+
+pub fn check_manual_map_err(
+    db: &dyn SemanticGroup,
+    arenas: &Arenas,
+    expr_match: &ExprMatch,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if check_manual(db, expr_match, arenas, ManualLint::ManualMapErr) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: expr_match.stable_ptr.untyped(),
+            message: MANUAL_MAP_ERR.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
@@ -7,7 +7,7 @@ use cairo_lang_syntax::node::TypedStablePtr;
 use crate::lints::manual::{check_manual, check_manual_if, ManualLint};
 
 pub const MANUAL_OK: &str = "Manual match for `ok` detected. Consider using `ok()` instead";
-pub(super) const LINT_NAME: &str = "manual_ok";
+pub(crate) const LINT_NAME: &str = "manual_ok";
 
 pub fn check_manual_ok(
     db: &dyn SemanticGroup,
@@ -15,7 +15,7 @@ pub fn check_manual_ok(
     expr_match: &ExprMatch,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    if check_manual(db, expr_match, arenas, ManualLint::ManualOk, LINT_NAME) {
+    if check_manual(db, expr_match, arenas, ManualLint::ManualOk) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_match.stable_ptr.untyped(),
             message: MANUAL_OK.to_owned(),
@@ -30,7 +30,7 @@ pub fn check_manual_if_ok(
     expr_if: &ExprIf,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    if check_manual_if(db, expr_if, arenas, ManualLint::ManualOk, LINT_NAME) {
+    if check_manual_if(db, expr_if, arenas, ManualLint::ManualOk) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_if.stable_ptr.untyped(),
             message: MANUAL_OK.to_owned(),
@@ -0,0 +1,60 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, ExprIf, ExprMatch};
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use crate::lints::manual::{check_manual, check_manual_if, ManualLint};
+
+pub const MANUAL_UNWRAP_OR: &str = "This can be done in one call with `.unwrap_or()`";
+pub const MANUAL_UNWRAP_OR_ELSE: &str = "This can be done in one call with `.unwrap_or_else()`";
+pub(crate) mod or {
+    pub(crate) const LINT_NAME: &str = "manual_unwrap_or";
+}
+pub(crate) mod or_else {
+    pub(crate) const LINT_NAME: &str = "manual_unwrap_or_else";
+}
+
+pub fn check_manual_unwrap_or(
+    db: &dyn SemanticGroup,
+    arenas: &Arenas,
+    expr_match: &ExprMatch,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if check_manual(db, expr_match, arenas, ManualLint::ManualUnwrapOr) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: expr_match.stable_ptr.untyped(),
+            message: MANUAL_UNWRAP_OR.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+    if check_manual(db, expr_match, arenas, ManualLint::ManualUnwrapOrElse) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: expr_match.stable_ptr.untyped(),
+            message: MANUAL_UNWRAP_OR_ELSE.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+pub fn check_manual_if_unwrap_or(
+    db: &dyn SemanticGroup,
+    arenas: &Arenas,
+    expr_if: &ExprIf,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if check_manual_if(db, expr_if, arenas, ManualLint::ManualUnwrapOr) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: expr_if.stable_ptr.untyped(),
+            message: MANUAL_UNWRAP_OR.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+    if check_manual_if(db, expr_if, arenas, ManualLint::ManualUnwrapOrElse) {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: expr_if.stable_ptr.untyped(),
+            message: MANUAL_UNWRAP_OR_ELSE.to_owned(),
+            severity: Severity::Warning,
+        });
+    }
+}
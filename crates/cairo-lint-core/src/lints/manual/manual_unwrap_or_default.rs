@@ -8,7 +8,7 @@ use crate::lints::manual::{check_manual, check_manual_if, ManualLint};
 
 pub const MANUAL_UNWRAP_OR_DEFAULT: &str =
     "This can be done in one call with `.unwrap_or_default()`";
-pub(super) const LINT_NAME: &str = "manual_unwrap_or_default";
+pub(crate) const LINT_NAME: &str = "manual_unwrap_or_default";
 
 pub fn check_manual_unwrap_or_default(
     db: &dyn SemanticGroup,
@@ -16,13 +16,7 @@ pub fn check_manual_unwrap_or_default(
     expr_match: &ExprMatch,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    if check_manual(
-        db,
-        expr_match,
-        arenas,
-        ManualLint::ManualUnwrapOrDefault,
-        LINT_NAME,
-    ) {
+    if check_manual(db, expr_match, arenas, ManualLint::ManualUnwrapOrDefault) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_match.stable_ptr.untyped(),
             message: MANUAL_UNWRAP_OR_DEFAULT.to_owned(),
@@ -37,13 +31,7 @@ pub fn check_manual_if_unwrap_or_default(
     expr_if: &ExprIf,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    if check_manual_if(
-        db,
-        expr_if,
-        arenas,
-        ManualLint::ManualUnwrapOrDefault,
-        LINT_NAME,
-    ) {
+    if check_manual_if(db, expr_if, arenas, ManualLint::ManualUnwrapOrDefault) {
         diagnostics.push(PluginDiagnostic {
             stable_ptr: expr_if.stable_ptr.untyped(),
             message: MANUAL_UNWRAP_OR_DEFAULT.to_owned(),
@@ -1,10 +1,16 @@
 pub mod helpers;
+pub mod manual_and_then;
 pub mod manual_err;
 pub mod manual_expect;
 pub mod manual_expect_err;
+pub mod manual_filter;
+pub mod manual_flatten;
 pub mod manual_is;
+pub mod manual_map;
+pub mod manual_map_err;
 pub mod manual_ok;
 pub mod manual_ok_or;
+pub mod manual_unwrap_or;
 pub mod manual_unwrap_or_default;
 
 use std::fmt::Debug;
@@ -12,8 +18,6 @@ use std::fmt::Debug;
 use cairo_lang_defs::ids::TopLevelLanguageElementId;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, Condition, Expr, ExprId, ExprIf, ExprMatch, MatchArm, Pattern};
-use cairo_lang_syntax::node::helpers::QueryAttrs;
-use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 use helpers::*;
 use if_chain::if_chain;
 
@@ -27,6 +31,13 @@ pub enum ManualLint {
     ManualIsNone,
     ManualExpect,
     ManualUnwrapOrDefault,
+    ManualUnwrapOr,
+    ManualUnwrapOrElse,
+    ManualMap,
+    ManualMapErr,
+    ManualAndThen,
+    ManualFilter,
+    ManualFlatten,
     ManualIsOk,
     ManualIsErr,
     ManualOptExpect,
@@ -36,7 +47,7 @@ pub enum ManualLint {
     ManualExpectErr,
 }
 
-pub const ALLOWED: [&str; 10] = [
+pub const ALLOWED: [&str; 17] = [
     manual_is::some::LINT_NAME,
     manual_is::none::LINT_NAME,
     manual_is::ok::LINT_NAME,
@@ -44,9 +55,16 @@ pub const ALLOWED: [&str; 10] = [
     manual_ok_or::LINT_NAME,
     manual_expect::LINT_NAME,
     manual_unwrap_or_default::LINT_NAME,
+    manual_unwrap_or::or::LINT_NAME,
+    manual_unwrap_or::or_else::LINT_NAME,
     manual_ok::LINT_NAME,
     manual_err::LINT_NAME,
     manual_expect_err::LINT_NAME,
+    manual_map::LINT_NAME,
+    manual_map_err::LINT_NAME,
+    manual_and_then::LINT_NAME,
+    manual_filter::LINT_NAME,
+    manual_flatten::LINT_NAME,
 ];
 
 /// Checks for all the manual lint written as `match`.
@@ -62,17 +80,7 @@ pub fn check_manual(
     expr_match: &ExprMatch,
     arenas: &Arenas,
     manual_lint: ManualLint,
-    lint_name: &str,
 ) -> bool {
-    // Checks if the lint is allowed in an upper scope.
-    let mut current_node = expr_match.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", lint_name) {
-            return false;
-        }
-        current_node = node;
-    }
-
     // All the manual lints are for options and results which only have 2 variants
     if expr_match.arms.len() != 2 {
         return false;
@@ -133,7 +141,29 @@ fn check_syntax_some_arm(
             };
             pattern_check_enum_arg(&arenas.patterns[arm.patterns[0]], &expr_var.var, arenas)
         }
-        ManualLint::ManualUnwrapOrDefault => {
+        ManualLint::ManualMap => is_mapped_variant(
+            db,
+            arm.expression,
+            &arenas.patterns[arm.patterns[0]],
+            arenas,
+            SOME,
+        ),
+        ManualLint::ManualAndThen => is_and_then_expr(
+            db,
+            arm.expression,
+            &arenas.patterns[arm.patterns[0]],
+            arenas,
+        ),
+        ManualLint::ManualFilter => is_filter_expr(
+            db,
+            arm.expression,
+            &arenas.patterns[arm.patterns[0]],
+            arenas,
+        ),
+        ManualLint::ManualUnwrapOrDefault
+        | ManualLint::ManualUnwrapOr
+        | ManualLint::ManualUnwrapOrElse
+        | ManualLint::ManualFlatten => {
             let Expr::Var(enum_destruct_var) = &arenas.exprs[arm.expression] else {
                 return false;
             };
@@ -189,6 +219,26 @@ fn check_syntax_ok_arm(
         ),
 
         ManualLint::ManualErr => is_expected_variant(&arm.expression, arenas, db, NONE),
+        ManualLint::ManualMap => is_mapped_variant(
+            db,
+            arm.expression,
+            &arenas.patterns[arm.patterns[0]],
+            arenas,
+            OK,
+        ),
+        ManualLint::ManualAndThen => is_and_then_expr(
+            db,
+            arm.expression,
+            &arenas.patterns[arm.patterns[0]],
+            arenas,
+        ),
+        ManualLint::ManualMapErr => is_destructured_variable_used_and_expected_variant(
+            &arenas.exprs[arm.expression],
+            &arenas.patterns[arm.patterns[0]],
+            db,
+            arenas,
+            OK,
+        ),
         ManualLint::ManualResExpect => {
             let Expr::Var(expr_var) = &arenas.exprs[arm.expression] else {
                 return false;
@@ -229,6 +279,12 @@ fn check_syntax_none_arm(
         ManualLint::ManualUnwrapOrDefault => {
             check_is_default(db, &arenas.exprs[*arm_expression], arenas)
         }
+        ManualLint::ManualUnwrapOr => is_unwrap_or_fallback(db, *arm_expression, arenas),
+        ManualLint::ManualUnwrapOrElse => is_unwrap_or_else_fallback(db, *arm_expression, arenas),
+        ManualLint::ManualMap
+        | ManualLint::ManualAndThen
+        | ManualLint::ManualFilter
+        | ManualLint::ManualFlatten => is_expected_variant(arm_expression, arenas, db, NONE),
         _ => false,
     }
 }
@@ -244,6 +300,22 @@ fn check_syntax_err_arm(
         ManualLint::ManualIsOk => is_expected_variant(&arm.expression, arenas, db, FALSE),
         ManualLint::ManualIsErr => is_expected_variant(&arm.expression, arenas, db, TRUE),
         ManualLint::ManualOk => is_expected_variant(&arm.expression, arenas, db, NONE),
+        ManualLint::ManualMap | ManualLint::ManualAndThen => {
+            is_destructured_variable_used_and_expected_variant(
+                &arenas.exprs[arm.expression],
+                &arenas.patterns[arm.patterns[0]],
+                db,
+                arenas,
+                ERR,
+            )
+        }
+        ManualLint::ManualMapErr => is_mapped_variant(
+            db,
+            arm.expression,
+            &arenas.patterns[arm.patterns[0]],
+            arenas,
+            ERR,
+        ),
         ManualLint::ManualErr => is_destructured_variable_used_and_expected_variant(
             &arenas.exprs[arm.expression],
             &arenas.patterns[arm.patterns[0]],
@@ -286,16 +358,7 @@ pub fn check_manual_if(
     expr: &ExprIf,
     arenas: &Arenas,
     manual_lint: ManualLint,
-    lint_name: &str,
 ) -> bool {
-    let mut current_node = expr.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", lint_name) {
-            return false;
-        }
-        current_node = node;
-    }
-
     if_chain! {
         if let Condition::Let(_condition_let, patterns) = &expr.condition;
         if let Pattern::EnumVariant(enum_pattern) = &arenas.patterns[patterns[0]];
@@ -330,6 +393,20 @@ fn check_syntax_opt_if(
     arenas: &Arenas,
     manual_lint: ManualLint,
 ) -> bool {
+    // The whole `if` block is moved in the closure of the combinators.
+    if let Condition::Let(_condition_let, patterns) = &expr.condition {
+        let pattern = &arenas.patterns[patterns[0]];
+        match manual_lint {
+            ManualLint::ManualMap => {
+                return is_mapped_variant(db, expr.if_block, pattern, arenas, SOME)
+            }
+            ManualLint::ManualAndThen => {
+                return is_and_then_expr(db, expr.if_block, pattern, arenas)
+            }
+            ManualLint::ManualFilter => return is_filter_expr(db, expr.if_block, pattern, arenas),
+            _ => {}
+        }
+    }
     let Expr::Block(if_block) = &arenas.exprs[expr.if_block] else {
         return false;
     };
@@ -346,7 +423,10 @@ fn check_syntax_opt_if(
         ManualLint::ManualIsSome => is_expected_variant(&tail_expr, arenas, db, TRUE),
         ManualLint::ManualIsNone => is_expected_variant(&tail_expr, arenas, db, FALSE),
         ManualLint::ManualOptExpect => if_expr_pattern_matches_tail_var(expr, arenas),
-        ManualLint::ManualUnwrapOrDefault => if_expr_pattern_matches_tail_var(expr, arenas),
+        ManualLint::ManualUnwrapOrDefault
+        | ManualLint::ManualUnwrapOr
+        | ManualLint::ManualUnwrapOrElse
+        | ManualLint::ManualFlatten => if_expr_pattern_matches_tail_var(expr, arenas),
         _ => false,
     }
 }
@@ -398,6 +478,16 @@ fn check_syntax_opt_else(
     arenas: &Arenas,
     manual_lint: ManualLint,
 ) -> bool {
+    // The whole `else` block is the fallback of `unwrap_or` and `unwrap_or_else`.
+    if let Some(else_block) = expr.else_block {
+        match manual_lint {
+            ManualLint::ManualUnwrapOr => return is_unwrap_or_fallback(db, else_block, arenas),
+            ManualLint::ManualUnwrapOrElse => {
+                return is_unwrap_or_else_fallback(db, else_block, arenas)
+            }
+            _ => {}
+        }
+    }
     let expr_block = match expr.else_block {
         Some(block) => {
             let Expr::Block(ref block) = arenas.exprs[block] else {
@@ -420,6 +510,10 @@ fn check_syntax_opt_else(
         ManualLint::ManualIsNone => is_expected_variant(&tail_expr_id, arenas, db, TRUE),
         ManualLint::ManualOptExpect => is_expected_function(tail_expr, db, PANIC_WITH_FELT252),
         ManualLint::ManualUnwrapOrDefault => check_is_default(db, tail_expr, arenas),
+        ManualLint::ManualMap
+        | ManualLint::ManualAndThen
+        | ManualLint::ManualFilter
+        | ManualLint::ManualFlatten => is_expected_variant(&tail_expr_id, arenas, db, NONE),
         _ => false,
     }
 }
@@ -1,22 +1,37 @@
 use cairo_lang_defs::ids::{FunctionWithBodyId, TopLevelLanguageElementId};
+use cairo_lang_filesystem::db::get_originating_location;
+use cairo_lang_filesystem::ids::FileKind;
 use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::FunctionId;
+use cairo_lang_semantic::{FunctionId, TypeId};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use num_bigint::BigInt;
 
+pub mod absurd_extreme_comparisons;
+pub mod array_init_then_append;
+pub mod array_param_only_read;
 pub mod bitwise_for_parity_check;
 pub mod bool_comparison;
+pub mod bool_simplification;
 pub mod breaks;
+pub mod div_before_mul;
 pub mod double_comparison;
 pub mod double_parens;
 pub mod duplicate_underscore_args;
 pub mod eq_op;
 pub mod erasing_op;
+pub mod felt252_arithmetic;
+pub mod identity_op;
 pub mod ifs;
 pub mod int_op_one;
 pub mod loops;
 pub mod manual;
 pub mod panic;
 pub mod performance;
+pub mod redundant_clone;
+pub mod returns;
 pub mod single_match;
+pub mod starknet_security;
+pub mod try_into_unwrap;
 
 pub(crate) const LE: &str = "core::traits::PartialOrd::le";
 pub(crate) const GE: &str = "core::traits::PartialOrd::ge";
@@ -28,9 +43,11 @@ pub(crate) const AND: &str = "core::traits::BitAnd::bitand";
 pub(crate) const OR: &str = "core::traits::BitOr::bitor";
 pub(crate) const XOR: &str = "core::traits::BitXor::bitxor";
 pub(crate) const NOT: &str = "core::traits::BitNot::bitnot";
+pub(crate) const BOOL_NOT: &str = "core::traits::Not::not";
 pub(crate) const DIV: &str = "core::traits::Div::div";
 pub(crate) const MUL: &str = "core::traits::Mul::mul";
 pub(crate) const SUB: &str = "core::traits::Sub::sub";
+pub(crate) const ADD: &str = "core::traits::Add::add";
 pub(crate) const OK: &str = "core::result::Result::Ok";
 pub(crate) const ERR: &str = "core::result::Result::Err";
 pub(crate) const SOME: &str = "core::option::Option::Some";
@@ -40,6 +57,10 @@ pub(crate) const FALSE: &str = "core::bool::False";
 pub(crate) const PANIC_WITH_FELT252: &str = "core::panic_with_felt252";
 pub(crate) const DEFAULT: &str = "core::traits::Default::default";
 pub(crate) const ARRAY_NEW: &str = "core::array::ArrayTrait::new";
+pub(crate) const ARRAY_APPEND: &str = "core::array::ArrayTrait::append";
+pub(crate) const TRY_INTO: &str = "core::traits::TryInto::try_into";
+pub(crate) const INTO: &str = "core::traits::Into::into";
+pub(crate) const UNWRAP: &str = "core::option::OptionTrait::unwrap";
 
 pub(crate) fn function_trait_name_from_fn_id(
     db: &dyn SemanticGroup,
@@ -58,3 +79,34 @@ pub(crate) fn function_trait_name_from_fn_id(
     // From the trait function id get the trait name and check if it's the corelib `BitAnd`
     trait_fn_id.full_path(db.upcast())
 }
+
+/// Gets the minimum and maximum values of an integer type.
+pub(crate) fn int_range(db: &dyn SemanticGroup, ty: TypeId) -> Option<(BigInt, BigInt)> {
+    let ty = ty.format(db);
+    let name = ty.strip_prefix("core::integer::")?;
+    let (is_signed, bits) = match name.split_at(1) {
+        ("u", bits) => (false, bits),
+        ("i", bits) => (true, bits),
+        _ => return None,
+    };
+    let bits: usize = bits.parse().ok()?;
+    let one = BigInt::from(1);
+    Some(if is_signed {
+        (-(&one << (bits - 1)), (&one << (bits - 1)) - 1)
+    } else {
+        (BigInt::from(0), (&one << bits) - 1)
+    })
+}
+
+/// Checks if the node was generated by a macro, i.e. if it comes from a virtual file that maps it
+/// to another file or from the expansion of an inline macro.
+pub(crate) fn is_macro_generated(db: &dyn SemanticGroup, stable_ptr: SyntaxStablePtrId) -> bool {
+    let file_id = stable_ptr.file_id(db.upcast());
+    let (originating_file_id, _) = get_originating_location(
+        db.upcast(),
+        file_id,
+        stable_ptr.lookup(db.upcast()).span(db.upcast()),
+        None,
+    );
+    originating_file_id != file_id || file_id.kind(db.upcast()) == FileKind::Expr
+}
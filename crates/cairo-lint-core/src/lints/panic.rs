@@ -4,14 +4,13 @@ use cairo_lang_diagnostics::Severity;
 use cairo_lang_filesystem::db::get_originating_location;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::ExprFunctionCall;
-use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 
 pub const PANIC_IN_CODE: &str = "Leaving `panic` in the code is discouraged.";
 const PANIC: &str = "core::panics::panic";
 pub const ALLOWED: [&str; 1] = [LINT_NAME];
-const LINT_NAME: &str = "panic";
+pub(crate) const LINT_NAME: &str = "panic";
 
 /// Checks for panic usage.
 pub fn check_panic_usage(
@@ -19,18 +18,10 @@ pub fn check_panic_usage(
     expr_function_call: &ExprFunctionCall,
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) {
-    // Checks if the lint is allowed in an upper scope
-    let mut current_node = expr_function_call
+    let init_node = expr_function_call
         .stable_ptr
         .lookup(db.upcast())
         .as_syntax_node();
-    let init_node = current_node.clone();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
 
     // If the function is not the panic function from the corelib return
     if expr_function_call.function.full_name(db) != PANIC {
@@ -66,14 +57,6 @@ pub fn check_panic_usage(
             if let Ok(file_node) = db.file_syntax(file_id);
             then {
                 let syntax_node = file_node.lookup_position(db.upcast(), text_position.start);
-                // Checks if the lint is allowed in the original file
-                let mut current_node = syntax_node.clone();
-                while let Some(node) = current_node.parent() {
-                    if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-                        return;
-                    }
-                    current_node = node;
-                }
                 diagnostics.push(PluginDiagnostic {
                     stable_ptr: syntax_node.stable_ptr(),
                     message: PANIC_IN_CODE.to_owned(),
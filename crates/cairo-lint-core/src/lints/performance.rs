@@ -1,15 +1,36 @@
+use std::collections::HashMap;
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, LanguageElementId};
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::{Arenas, Condition, Expr, ExprWhile};
-use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_semantic::{
+    Arenas, Condition, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, ExprWhile, VarId,
+};
+use cairo_lang_syntax::node::ast::{self, BinaryOperator};
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 
-const INEFFICIENT_WHILE_COMP_MESSAGE: &str = "using [`<`, `<=`, `>=`, `>`] exit conditions is inefficient. Consider \
+use super::function_trait_name_from_fn_id;
+use super::starknet_security::{is_external_call, is_storage_read, is_storage_write};
+use crate::analysis::{contains, location};
+
+pub const INEFFICIENT_WHILE_COMP_MESSAGE: &str = "using [`<`, `<=`, `>=`, `>`] exit conditions is inefficient. Consider \
                                               switching to `!=` or using ArrayTrait::multi_pop_front.";
+pub const STORAGE_READ_IN_LOOP: &str =
+    "Storage read of the same address in every iteration of the loop. Consider reading it once \
+     before the loop.";
+
+pub const ALLOWED: [&str; 2] = [
+    inefficient_while_comp::LINT_NAME,
+    storage_read_in_loop::LINT_NAME,
+];
 
-pub const ALLOWED: [&str; 1] = [LINT_NAME];
-const LINT_NAME: &str = "inefficient_while_comp";
+pub(crate) mod inefficient_while_comp {
+    pub(crate) const LINT_NAME: &str = "inefficient_while_comp";
+}
+pub(crate) mod storage_read_in_loop {
+    pub(crate) const LINT_NAME: &str = "storage_read_in_loop";
+}
 // Match all types implementing PartialOrd
 const PARTIAL_ORD_PATTERNS: [&str; 4] = [
     "PartialOrd::lt\"",
@@ -24,14 +45,6 @@ pub fn check_inefficient_while_comp(
     diagnostics: &mut Vec<PluginDiagnostic>,
     arenas: &Arenas,
 ) {
-    // Checks if the lint is allowed in an upper scope.
-    let mut current_node = expr_while.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
     // It might be a false positive, because there can be cases when:
     //  - The rhs arguments is changed in the loop body
     //  - The lhs argument can "skip" the moment where lhs == rhs
@@ -64,3 +77,174 @@ fn check_expression(
         _ => {}
     }
 }
+
+/// Checks for storage reads in the body of a `loop`, `while` or `for` whose storage address (i.e.
+/// the receiver and the keys) doesn't change across iterations, e.g. `self.fee.read()`.
+///
+/// The address is considered as changing if it depends on a variable declared, assigned or passed
+/// by reference in the loop, and the read is skipped if the same storage field is written in the
+/// loop, whatever the keys of the write. No read is reported if the loop calls a function of the
+/// crate that may write the storage, or another contract that may re-enter the contract.
+pub fn check_storage_read_in_loop(
+    db: &dyn SemanticGroup,
+    loop_expr: &Expr,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let loop_location = location(db, loop_expr.stable_ptr().untyped());
+
+    let mut inner_loops = Vec::new();
+    let mut mutated_vars = Vec::new();
+    let mut written_fields = Vec::new();
+    let mut reads = Vec::new();
+    let mut analyzed_functions = HashMap::new();
+    for (_expr_id, expr) in &arenas.exprs {
+        let expr_location = location(db, expr.stable_ptr().untyped());
+        if !contains(loop_location, expr_location) {
+            continue;
+        }
+        match expr {
+            Expr::Loop(_) | Expr::While(_) | Expr::For(_) if expr_location != loop_location => {
+                inner_loops.push(expr_location)
+            }
+            Expr::Assignment(assignment) => mutated_vars.push(assignment.ref_arg.base_var()),
+            Expr::FunctionCall(expr_func) => {
+                // `deref_mut` is called on `ref self` to access the storage, it doesn't modify it.
+                if !function_trait_name_from_fn_id(db, &expr_func.function)
+                    .ends_with("::DerefMut::deref_mut")
+                {
+                    mutated_vars.extend(expr_func.args.iter().filter_map(|arg| match arg {
+                        ExprFunctionCallArg::Reference(ref_arg) => Some(ref_arg.base_var()),
+                        ExprFunctionCallArg::Value(_) => None,
+                    }));
+                }
+                if is_storage_write(db, expr_func) {
+                    written_fields.extend(storage_field(db, expr_func));
+                } else if is_storage_read(db, expr_func) {
+                    reads.push((expr_func, expr_location));
+                } else if may_write_storage(db, expr_func, &mut analyzed_functions) {
+                    return;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let is_loop_variable = |var: &VarId| match var {
+        VarId::Local(local_var) => {
+            mutated_vars.contains(var)
+                || contains(
+                    loop_location,
+                    location(db, local_var.untyped_stable_ptr(db.upcast())),
+                )
+        }
+        _ => mutated_vars.contains(var),
+    };
+    for (expr_func, read_location) in reads {
+        // Reads in a nested loop are reported for the innermost loop only.
+        if inner_loops
+            .iter()
+            .any(|inner_loop| contains(*inner_loop, read_location))
+        {
+            continue;
+        }
+        let Some(field) = storage_field(db, expr_func) else {
+            continue;
+        };
+        let mut vars = Vec::new();
+        if collect_call_vars(expr_func, arenas, &mut vars)
+            && !vars.iter().any(&is_loop_variable)
+            && !written_fields.contains(&field)
+        {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: expr_func.stable_ptr.untyped(),
+                message: STORAGE_READ_IN_LOOP.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Checks if the call may write the contract storage, i.e. if it calls a function of the crate
+/// writing it, directly or through other calls, or another contract which could re-enter this one.
+fn may_write_storage(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    analyzed_functions: &mut HashMap<FunctionWithBodyId, bool>,
+) -> bool {
+    if is_external_call(db, expr_func) {
+        return true;
+    }
+    if expr_func.function.full_name(db).starts_with("core::") {
+        return false;
+    }
+    let Ok(Some(callee)) = expr_func.function.get_concrete(db).body(db) else {
+        return false;
+    };
+    let callee = callee.function_with_body_id(db);
+    if let Some(writes) = analyzed_functions.get(&callee) {
+        return *writes;
+    }
+    analyzed_functions.insert(callee, false);
+    let writes = db.function_body(callee).is_ok_and(|function_body| {
+        function_body.arenas.exprs.iter().any(|(_expr_id, expr)| {
+            matches!(expr, Expr::FunctionCall(expr_func)
+                if is_storage_write(db, expr_func)
+                    || may_write_storage(db, expr_func, analyzed_functions))
+        })
+    });
+    analyzed_functions.insert(callee, writes);
+    writes
+}
+
+/// Collects the variables the expression depends on. Returns `false` if the expression isn't made
+/// only of variables, constants, member accesses and function calls.
+fn collect_vars(expr_id: ExprId, arenas: &Arenas, vars: &mut Vec<VarId>) -> bool {
+    match &arenas.exprs[expr_id] {
+        Expr::Var(expr_var) => {
+            vars.push(expr_var.var);
+            true
+        }
+        Expr::Literal(_) | Expr::StringLiteral(_) | Expr::Constant(_) => true,
+        Expr::Snapshot(snapshot) => collect_vars(snapshot.inner, arenas, vars),
+        Expr::Desnap(desnap) => collect_vars(desnap.inner, arenas, vars),
+        Expr::MemberAccess(member_access) => collect_vars(member_access.expr, arenas, vars),
+        Expr::FunctionCall(expr_func) => collect_call_vars(expr_func, arenas, vars),
+        _ => false,
+    }
+}
+
+fn collect_call_vars(expr_func: &ExprFunctionCall, arenas: &Arenas, vars: &mut Vec<VarId>) -> bool {
+    expr_func.args.iter().all(|arg| match arg {
+        ExprFunctionCallArg::Value(expr_id) => collect_vars(*expr_id, arenas, vars),
+        ExprFunctionCallArg::Reference(ref_arg) => {
+            vars.push(ref_arg.base_var());
+            true
+        }
+    })
+}
+
+/// Gets the text of the storage field accessed by a storage access, e.g. `self.balances` for
+/// `self.balances.read(user)` and `self.balances.entry(user).read()`.
+fn storage_field(db: &dyn SemanticGroup, expr_func: &ExprFunctionCall) -> Option<String> {
+    let ast::Expr::Binary(access) = expr_func.stable_ptr.lookup(db.upcast()) else {
+        return None;
+    };
+    if !is_member_access(db, &access) {
+        return None;
+    }
+    let mut field = access.lhs(db.upcast());
+    while let ast::Expr::Binary(inner_access) = &field {
+        match inner_access.lhs(db.upcast()) {
+            ast::Expr::Binary(lhs) if is_member_access(db, &lhs) => {
+                field = ast::Expr::Binary(lhs);
+            }
+            _ => break,
+        }
+    }
+    Some(field.as_syntax_node().get_text_without_trivia(db.upcast()))
+}
+
+fn is_member_access(db: &dyn SemanticGroup, expr: &ast::ExprBinary) -> bool {
+    matches!(expr.op(db.upcast()), BinaryOperator::Dot(_))
+}
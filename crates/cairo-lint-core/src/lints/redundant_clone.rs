@@ -0,0 +1,60 @@
+use cairo_lang_defs::ids::FunctionWithBodyId;
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCallArg};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use super::{function_trait_name_from_fn_id, is_macro_generated};
+use crate::analysis::liveness::Liveness;
+
+pub const REDUNDANT_CLONE: &str =
+    "Redundant clone: the cloned variable isn't used afterwards. Consider removing the `.clone()` \
+     call to move the variable instead.";
+
+pub const ALLOWED: [&str; 1] = [LINT_NAME];
+pub(crate) const LINT_NAME: &str = "redundant_clone";
+
+const CLONE: &str = "core::clone::Clone::clone";
+
+/// Checks for `variable.clone()` calls on variables that aren't used afterwards, which could be
+/// moved instead of being cloned.
+pub fn check_redundant_clone(
+    db: &dyn SemanticGroup,
+    func_id: FunctionWithBodyId,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let mut liveness = None;
+    for (_expr_id, expr) in &arenas.exprs {
+        let Expr::FunctionCall(expr_func) = expr else {
+            continue;
+        };
+        let stable_ptr = expr_func.stable_ptr.untyped();
+        if function_trait_name_from_fn_id(db, &expr_func.function) != CLONE
+            || stable_ptr.lookup(db.upcast()).kind(db.upcast()) != SyntaxKind::ExprBinary
+            || is_macro_generated(db, stable_ptr)
+        {
+            continue;
+        }
+        // The receiver of the method call is snapshotted, unless it's already a snapshot.
+        let Some(ExprFunctionCallArg::Value(receiver)) = expr_func.args.first() else {
+            continue;
+        };
+        let Expr::Snapshot(snapshot) = &arenas.exprs[*receiver] else {
+            continue;
+        };
+        let Expr::Var(expr_var) = &arenas.exprs[snapshot.inner] else {
+            continue;
+        };
+        let liveness = liveness.get_or_insert_with(|| Liveness::new(db, func_id, arenas));
+        if !liveness.is_live_after(db, expr_var.var, stable_ptr) {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr,
+                message: REDUNDANT_CLONE.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
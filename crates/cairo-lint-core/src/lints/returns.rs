@@ -0,0 +1,99 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprBlock, ExprId, ExprVar, Pattern, Statement, VarId};
+use cairo_lang_syntax::node::ast::{self, OptionTypeClause};
+use cairo_lang_syntax::node::TypedStablePtr;
+use if_chain::if_chain;
+
+use super::is_macro_generated;
+
+pub const NEEDLESS_RETURN: &str = "Unnecessary `return` at the end of the function. Consider \
+                                   using the returned value as the tail expression instead.";
+pub const LET_AND_RETURN: &str = "Variable returned right after being bound with `let`. Consider \
+                                  returning the expression directly.";
+
+pub const ALLOWED: [&str; 2] = [needless_return::LINT_NAME, let_and_return::LINT_NAME];
+
+pub(crate) mod needless_return {
+    pub(crate) const LINT_NAME: &str = "needless_return";
+}
+pub(crate) mod let_and_return {
+    pub(crate) const LINT_NAME: &str = "let_and_return";
+}
+
+/// Checks for `return` statements in tail position of the function body, i.e. at the end of its
+/// block or at the end of the branches of its tail `if` or `match`.
+pub fn check_needless_return(
+    db: &dyn SemanticGroup,
+    expr_id: ExprId,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    match &arenas.exprs[expr_id] {
+        Expr::Block(expr_block) => {
+            if let Some(tail) = expr_block.tail {
+                return check_needless_return(db, tail, arenas, diagnostics);
+            }
+            let Some(Statement::Return(statement_return)) = expr_block
+                .statements
+                .last()
+                .map(|statement_id| &arenas.statements[*statement_id])
+            else {
+                return;
+            };
+            if !is_macro_generated(db, statement_return.stable_ptr.untyped()) {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: statement_return.stable_ptr.untyped(),
+                    message: NEEDLESS_RETURN.to_string(),
+                    severity: Severity::Warning,
+                });
+            }
+        }
+        Expr::If(expr_if) => {
+            check_needless_return(db, expr_if.if_block, arenas, diagnostics);
+            if let Some(else_block) = expr_if.else_block {
+                check_needless_return(db, else_block, arenas, diagnostics);
+            }
+        }
+        Expr::Match(expr_match) => {
+            for arm in &expr_match.arms {
+                check_needless_return(db, arm.expression, arenas, diagnostics);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Checks for a block ending with a variable bound by the `let` statement right before it.
+///
+/// ```ignore
+/// let res = compute();
+/// res
+/// ```
+pub fn check_let_and_return(
+    db: &dyn SemanticGroup,
+    expr_block: &ExprBlock,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if_chain! {
+        if let Some(tail) = expr_block.tail;
+        if let Expr::Var(ExprVar { var: VarId::Local(tail_var), .. }) = &arenas.exprs[tail];
+        if let Some(statement_id) = expr_block.statements.last();
+        if let Statement::Let(statement_let) = &arenas.statements[*statement_id];
+        if let Pattern::Variable(pattern) = &arenas.patterns[statement_let.pattern];
+        if pattern.var.id == *tail_var;
+        if !is_macro_generated(db, statement_let.stable_ptr.untyped());
+        // The type annotation could be needed to infer the type of the expression.
+        if let ast::Statement::Let(let_syntax) = statement_let.stable_ptr.lookup(db.upcast());
+        if let OptionTypeClause::Empty(_) = let_syntax.type_clause(db.upcast());
+        then {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: statement_let.stable_ptr.untyped(),
+                message: LET_AND_RETURN.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
@@ -4,7 +4,6 @@ use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::{Arenas, ExprMatch, Pattern};
 use cairo_lang_syntax::node::ast::{Expr as AstExpr, ExprBlock, ExprListParenthesized, Statement};
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 use if_chain::if_chain;
 
@@ -14,7 +13,7 @@ pub const MATCH_FOR_EQUALITY: &str =
     "you seem to be trying to use `match` for an equality check. Consider using `if`";
 
 pub const ALLOWED: [&str; 1] = [LINT_NAME];
-const LINT_NAME: &str = "single_match";
+pub(crate) const LINT_NAME: &str = "single_match";
 
 /// Checks for matches that do something only in 1 arm and can be rewrote as an `if let`
 /// ```ignore
@@ -36,15 +35,6 @@ pub fn check_single_match(
     diagnostics: &mut Vec<PluginDiagnostic>,
     arenas: &Arenas,
 ) {
-    // Checks if the lint is allowed in an upper scope.
-    let mut current_node = match_expr.stable_ptr.lookup(db.upcast()).as_syntax_node();
-    while let Some(node) = current_node.parent() {
-        if node.has_attr_with_arg(db.upcast(), "allow", LINT_NAME) {
-            return;
-        }
-        current_node = node;
-    }
-
     let arms = &match_expr.arms;
     let mut is_single_armed = false;
     let mut is_complete = false;
@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, NamedLanguageElementId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, VarId};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use crate::analysis::def_use::{DefUse, DefinitionId, DefinitionKind};
+use crate::lints::{function_trait_name_from_fn_id, AND, EQ, GE, GT, LE, LT, NE};
+
+pub const BLOCK_INFO_RANDOMNESS: &str =
+    "Block timestamp or number used as a source of randomness, while the sequencer can influence \
+     it. Consider using a verifiable source of randomness instead.";
+pub const BLOCK_INFO_STRICT_EQUALITY: &str =
+    "Block timestamp or number compared with a strict equality, while the sequencer can influence \
+     it. Consider comparing it with `<`, `<=`, `>` or `>=` instead.";
+
+pub(crate) const LINT_NAME: &str = "block_info_dependence";
+
+/// Functions returning the block info, or the execution info containing it.
+const BLOCK_INFO_SOURCES: [&str; 4] = [
+    "core::starknet::info::get_block_timestamp",
+    "core::starknet::info::get_block_number",
+    "core::starknet::info::get_block_info",
+    "core::starknet::info::get_execution_info",
+];
+/// Members leading to the block timestamp or number from the execution info.
+const BLOCK_INFO_MEMBERS: [&str; 3] = ["block_info", "block_timestamp", "block_number"];
+const REM: &str = "core::traits::Rem::rem";
+const DIV_REM: &str = "core::traits::DivRem::div_rem";
+/// Comparisons, whose boolean results don't carry the compared values.
+const COMPARISONS: [&str; 6] = [EQ, NE, LT, LE, GT, GE];
+const HASH_MODULES: [&str; 4] = [
+    "core::hash::",
+    "core::poseidon::",
+    "core::pedersen::",
+    "core::keccak::",
+];
+
+/// Checks for the block timestamp or number, or a hash of them, bounded with a modulo or a mask
+/// (e.g. `get_block_timestamp() % 2`), and for the block timestamp or number compared with a
+/// strict equality (e.g. `get_block_number() == deadline`).
+///
+/// The values are tracked through the definitions of the variables reaching their uses: the
+/// variables bound to them (with `let` or a pattern) or assigned them, and the ones passed by
+/// reference along with them to a corelib function (e.g. an array they are appended to). The
+/// results of comparisons are booleans that don't carry the value, so they aren't tracked.
+pub fn check_block_info_dependence(
+    db: &dyn SemanticGroup,
+    func_id: FunctionWithBodyId,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    // Most functions don't use the block info, so the definitions aren't computed for them.
+    if !arenas.exprs.iter().any(|(_expr_id, expr)| {
+        matches!(expr, Expr::FunctionCall(expr_func) if is_block_info_source(db, expr_func))
+    }) {
+        return;
+    }
+    let def_use = DefUse::new(db, func_id, arenas);
+    let taint = tainted_definitions(db, arenas, &def_use);
+    for (_expr_id, expr) in &arenas.exprs {
+        let Expr::FunctionCall(expr_func) = expr else {
+            continue;
+        };
+        let Some(args_taint) = args_taint(db, expr_func, arenas, &taint) else {
+            continue;
+        };
+        let message = if is_bounding_operation(db, expr_func) {
+            BLOCK_INFO_RANDOMNESS
+        } else if args_taint == TaintKind::Direct
+            && [EQ, NE].contains(&function_trait_name_from_fn_id(db, &expr_func.function).as_str())
+            // A comparison of a random value (e.g. `timestamp % 2 == 0`) is already reported.
+            && !expr_func.args.iter().any(|arg| {
+                matches!(arg, ExprFunctionCallArg::Value(expr_id)
+                    if is_bounding_operation_result(db, *expr_id, arenas))
+            })
+        {
+            BLOCK_INFO_STRICT_EQUALITY
+        } else {
+            continue;
+        };
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: expr_func.stable_ptr.untyped(),
+            message: message.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Checks if the function call bounds its operand to a range (a modulo or a mask), i.e. an
+/// operation used to turn a value into a random one.
+fn is_bounding_operation(db: &dyn SemanticGroup, expr_func: &ExprFunctionCall) -> bool {
+    [REM, DIV_REM, AND].contains(&function_trait_name_from_fn_id(db, &expr_func.function).as_str())
+}
+
+fn is_bounding_operation_result(db: &dyn SemanticGroup, expr_id: ExprId, arenas: &Arenas) -> bool {
+    match &arenas.exprs[expr_id] {
+        Expr::Snapshot(snapshot) => is_bounding_operation_result(db, snapshot.inner, arenas),
+        Expr::FunctionCall(expr_func) => is_bounding_operation(db, expr_func),
+        _ => false,
+    }
+}
+
+fn is_block_info_source(db: &dyn SemanticGroup, expr_func: &ExprFunctionCall) -> bool {
+    BLOCK_INFO_SOURCES.contains(&expr_func.function.full_name(db).as_str())
+}
+
+fn is_hash(db: &dyn SemanticGroup, expr_func: &ExprFunctionCall) -> bool {
+    let trait_function = function_trait_name_from_fn_id(db, &expr_func.function);
+    let function_name = expr_func.function.full_name(db);
+    HASH_MODULES
+        .iter()
+        .any(|module| trait_function.starts_with(module) || function_name.starts_with(module))
+}
+
+/// How a value is derived from the block info.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum TaintKind {
+    /// A hash of the block info, which is only reported once bounded (e.g. with `%`).
+    Hashed,
+    /// The block info itself, or a value computed from it without hashing it.
+    Direct,
+}
+
+/// The definitions giving a value derived from the block info to their variable.
+struct Taint<'a> {
+    def_use: &'a DefUse,
+    definitions: HashMap<DefinitionId, TaintKind>,
+}
+
+impl Taint<'_> {
+    /// Gets how the variable may be derived from the block info when used by the node, if it is.
+    fn var_taint(
+        &self,
+        db: &dyn SemanticGroup,
+        var: VarId,
+        stable_ptr: SyntaxStablePtrId,
+    ) -> Option<TaintKind> {
+        self.def_use
+            .reaching_definitions(db, var, stable_ptr)
+            .iter()
+            .filter_map(|definition_id| self.definitions.get(definition_id).copied())
+            .max()
+    }
+}
+
+fn tainted_definitions<'a>(
+    db: &dyn SemanticGroup,
+    arenas: &Arenas,
+    def_use: &'a DefUse,
+) -> Taint<'a> {
+    let mut taint = Taint {
+        def_use,
+        definitions: HashMap::new(),
+    };
+    // A definition can be tainted by another one evaluated later, e.g. in a loop, so the
+    // definitions are collected until reaching a fixed point.
+    loop {
+        let mut changed = false;
+        for (definition_id, definition) in def_use.definitions().iter().enumerate() {
+            let definition_taint = match definition.kind {
+                DefinitionKind::Param => None,
+                DefinitionKind::Let(expr_id)
+                | DefinitionKind::Pattern(expr_id)
+                | DefinitionKind::Assignment(expr_id) => expr_taint(db, expr_id, arenas, &taint),
+                DefinitionKind::Reference(call) => match &arenas.exprs[call] {
+                    Expr::FunctionCall(expr_func)
+                        if expr_func.function.full_name(db).starts_with("core::") =>
+                    {
+                        args_taint(db, expr_func, arenas, &taint)
+                    }
+                    _ => None,
+                },
+            };
+            if let Some(kind) = definition_taint {
+                if taint
+                    .definitions
+                    .get(&definition_id)
+                    .is_none_or(|known_kind| *known_kind < kind)
+                {
+                    taint.definitions.insert(definition_id, kind);
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            return taint;
+        }
+    }
+}
+
+fn args_taint(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    arenas: &Arenas,
+    taint: &Taint<'_>,
+) -> Option<TaintKind> {
+    expr_func
+        .args
+        .iter()
+        .filter_map(|arg| match arg {
+            ExprFunctionCallArg::Value(expr_id) => expr_taint(db, *expr_id, arenas, taint),
+            ExprFunctionCallArg::Reference(ref_arg) => {
+                taint.var_taint(db, ref_arg.base_var(), expr_func.stable_ptr.untyped())
+            }
+        })
+        .max()
+}
+
+/// Gets how the value of the expression is derived from the block info, if it is.
+fn expr_taint(
+    db: &dyn SemanticGroup,
+    expr_id: ExprId,
+    arenas: &Arenas,
+    taint: &Taint<'_>,
+) -> Option<TaintKind> {
+    match &arenas.exprs[expr_id] {
+        Expr::FunctionCall(expr_func) => {
+            if is_block_info_source(db, expr_func) {
+                return Some(TaintKind::Direct);
+            }
+            if COMPARISONS
+                .contains(&function_trait_name_from_fn_id(db, &expr_func.function).as_str())
+            {
+                return None;
+            }
+            let kind = args_taint(db, expr_func, arenas, taint)?;
+            Some(if is_hash(db, expr_func) {
+                TaintKind::Hashed
+            } else {
+                kind
+            })
+        }
+        Expr::Var(expr_var) => taint.var_taint(db, expr_var.var, expr_var.stable_ptr.untyped()),
+        Expr::Snapshot(snapshot) => expr_taint(db, snapshot.inner, arenas, taint),
+        Expr::Desnap(desnap) => expr_taint(db, desnap.inner, arenas, taint),
+        // Only the members leading to the block timestamp or number are tracked, e.g. not the
+        // caller address of the execution info.
+        Expr::MemberAccess(member_access) => {
+            if BLOCK_INFO_MEMBERS.contains(&member_access.member.name(db.upcast()).as_str()) {
+                expr_taint(db, member_access.expr, arenas, taint)
+            } else {
+                None
+            }
+        }
+        Expr::Block(expr_block) => expr_block
+            .tail
+            .and_then(|tail| expr_taint(db, tail, arenas, taint)),
+        _ => None,
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, ParamId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::constant::ConstValue;
+use cairo_lang_semantic::{
+    Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, ExprVar, GenericArgumentId, VarId,
+};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::TypedStablePtr;
+use num_bigint::BigInt;
+
+use super::{is_abi_function, is_function_named, is_storage_write};
+use crate::analysis::execution_order::ExecutionOrder;
+use crate::lints::{function_trait_name_from_fn_id, EQ, NE};
+
+pub const MISSING_ZERO_ADDRESS_CHECK: &str =
+    "Address parameter written to the storage without checking that it isn't zero. Consider \
+     asserting that it's not zero (e.g. with `is_non_zero()`) before writing it.";
+
+pub(crate) const LINT_NAME: &str = "missing_zero_address_check";
+
+const CONTRACT_ADDRESS: &str = "core::starknet::contract_address::ContractAddress";
+const CONTRACT_ADDRESS_CONST: &str = "core::starknet::contract_address::contract_address_const";
+/// Functions of the `Zero` and `Zeroable` traits checking if a value is zero.
+const ZERO_CHECKS: [&str; 4] = [
+    "Zero::is_zero",
+    "Zero::is_non_zero",
+    "Zeroable::is_zero",
+    "Zeroable::is_non_zero",
+];
+/// Functions of the `Zero` and `Zeroable` traits returning zero.
+const ZEROS: [&str; 2] = ["Zero::zero", "Zeroable::zero"];
+
+/// Checks for `ContractAddress` parameters of the functions of the ABI of a contract (entry points,
+/// constructor and L1 handlers) written to the storage (e.g. `self.owner.write(owner)`) without
+/// being checked against zero before. The writes done by the functions the parameter is passed to
+/// (e.g. `self._transfer_ownership(owner)`) are followed, so that the internal functions are
+/// analyzed with the checks of their callers.
+///
+/// The parameter is checked by calling `is_zero()` or `is_non_zero()` on it, by comparing it with
+/// zero (e.g. `owner != Zero::zero()`) or by passing it to one of the functions listed in
+/// `zero_address_guard_functions`, by name or full path.
+pub fn check_missing_zero_address_check(
+    db: &dyn SemanticGroup,
+    func_id: FunctionWithBodyId,
+    arenas: &Arenas,
+    guard_functions: &[String],
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if !is_abi_function(db, func_id) {
+        return;
+    }
+    let Ok(signature) = db.function_with_body_signature(func_id) else {
+        return;
+    };
+    let mut analyzed_params = HashMap::new();
+    for param in signature.params {
+        if param.ty.format(db) != CONTRACT_ADDRESS {
+            continue;
+        }
+        for stable_ptr in
+            unchecked_writes(db, arenas, param.id, guard_functions, &mut analyzed_params)
+        {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr,
+                message: MISSING_ZERO_ADDRESS_CHECK.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Finds the storage writes of the parameter, and the calls passing it to a function writing it
+/// unchecked, that aren't preceded by a check that it isn't zero.
+///
+/// `analyzed_params` memoizes whether the parameters of the functions already analyzed are written
+/// unchecked, parameters still being analyzed (i.e. recursive calls) are considered as checked.
+fn unchecked_writes(
+    db: &dyn SemanticGroup,
+    arenas: &Arenas,
+    param: ParamId,
+    guard_functions: &[String],
+    analyzed_params: &mut HashMap<ParamId, bool>,
+) -> Vec<SyntaxStablePtrId> {
+    let mut checks = Vec::new();
+    let mut writes = Vec::new();
+    for (_expr_id, expr) in &arenas.exprs {
+        let Expr::FunctionCall(expr_func) = expr else {
+            continue;
+        };
+        if is_zero_check(db, expr_func, param, arenas, guard_functions) {
+            checks.push(expr_func.stable_ptr.untyped());
+        } else if (is_storage_write(db, expr_func)
+            && matches!(expr_func.args.last(), Some(ExprFunctionCallArg::Value(value))
+                if is_param(*value, param, arenas)))
+            || passes_to_unchecked_write(
+                db,
+                expr_func,
+                param,
+                arenas,
+                guard_functions,
+                analyzed_params,
+            )
+        {
+            writes.push(expr_func.stable_ptr.untyped());
+        }
+    }
+    let execution_order = ExecutionOrder::new(db, arenas);
+    writes
+        .into_iter()
+        .filter(|write| {
+            !checks
+                .iter()
+                .any(|check| execution_order.always_runs_before(db, *check, *write))
+        })
+        .collect()
+}
+
+/// Checks if the call passes the parameter to a function of the crate writing it unchecked.
+fn passes_to_unchecked_write(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    param: ParamId,
+    arenas: &Arenas,
+    guard_functions: &[String],
+    analyzed_params: &mut HashMap<ParamId, bool>,
+) -> bool {
+    if expr_func.function.full_name(db).starts_with("core::") {
+        return false;
+    }
+    let Ok(Some(callee)) = expr_func.function.get_concrete(db).body(db) else {
+        return false;
+    };
+    let callee = callee.function_with_body_id(db);
+    let (Ok(signature), Ok(function_body)) = (
+        db.function_with_body_signature(callee),
+        db.function_body(callee),
+    ) else {
+        return false;
+    };
+    expr_func
+        .args
+        .iter()
+        .zip(signature.params)
+        .any(|(arg, callee_param)| {
+            if !matches!(arg, ExprFunctionCallArg::Value(value) if is_param(*value, param, arenas))
+            {
+                return false;
+            }
+            if let Some(unchecked) = analyzed_params.get(&callee_param.id) {
+                return *unchecked;
+            }
+            analyzed_params.insert(callee_param.id, false);
+            let unchecked = !unchecked_writes(
+                db,
+                &function_body.arenas,
+                callee_param.id,
+                guard_functions,
+                analyzed_params,
+            )
+            .is_empty();
+            analyzed_params.insert(callee_param.id, unchecked);
+            unchecked
+        })
+}
+
+/// Checks if the call checks that the parameter isn't zero, with the `Zero` traits, a comparison
+/// or a guard function.
+fn is_zero_check(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    param: ParamId,
+    arenas: &Arenas,
+    guard_functions: &[String],
+) -> bool {
+    let trait_function = function_trait_name_from_fn_id(db, &expr_func.function);
+    let args = expr_func
+        .args
+        .iter()
+        .filter_map(|arg| match arg {
+            ExprFunctionCallArg::Value(expr_id) => Some(*expr_id),
+            ExprFunctionCallArg::Reference(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let has_param_arg = args.iter().any(|arg| is_param(*arg, param, arenas));
+    if ZERO_CHECKS
+        .iter()
+        .any(|check| is_function_named(&trait_function, check))
+    {
+        return has_param_arg;
+    }
+    if [EQ, NE].contains(&trait_function.as_str()) {
+        return has_param_arg && args.iter().any(|arg| is_zero(db, *arg, arenas));
+    }
+    let function_name = expr_func.function.full_name(db);
+    has_param_arg
+        && guard_functions
+            .iter()
+            .any(|guard| is_function_named(&function_name, guard))
+}
+
+/// Checks if the expression is the zero address, e.g. `Zero::zero()` or
+/// `contract_address_const::<0>()`.
+fn is_zero(db: &dyn SemanticGroup, expr_id: ExprId, arenas: &Arenas) -> bool {
+    match &arenas.exprs[expr_id] {
+        Expr::Snapshot(snapshot) => is_zero(db, snapshot.inner, arenas),
+        Expr::FunctionCall(expr_func) => {
+            let trait_function = function_trait_name_from_fn_id(db, &expr_func.function);
+            ZEROS
+                .iter()
+                .any(|zero| is_function_named(&trait_function, zero))
+                || is_zero_address_const(db, expr_func)
+        }
+        _ => false,
+    }
+}
+
+fn is_zero_address_const(db: &dyn SemanticGroup, expr_func: &ExprFunctionCall) -> bool {
+    let concrete_function = expr_func.function.get_concrete(db);
+    let [GenericArgumentId::Constant(address)] = concrete_function.generic_args.as_slice() else {
+        return false;
+    };
+    expr_func
+        .function
+        .full_name(db)
+        .starts_with(CONTRACT_ADDRESS_CONST)
+        && matches!(db.lookup_intern_const_value(*address),
+            ConstValue::Int(value, _) if value == BigInt::from(0))
+}
+
+/// Checks if the expression is the parameter, possibly snapshotted.
+fn is_param(expr_id: ExprId, param: ParamId, arenas: &Arenas) -> bool {
+    match &arenas.exprs[expr_id] {
+        Expr::Snapshot(snapshot) => is_param(snapshot.inner, param, arenas),
+        Expr::Desnap(desnap) => is_param(desnap.inner, param, arenas),
+        Expr::Var(ExprVar {
+            var: VarId::Param(id),
+            ..
+        }) => *id == param,
+        _ => false,
+    }
+}
@@ -0,0 +1,152 @@
+use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleId};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::ExprFunctionCall;
+use cairo_lang_syntax::node::ast;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+
+use super::function_trait_name_from_fn_id;
+
+pub mod block_info_dependence;
+pub mod missing_zero_address_check;
+pub mod storage_write_after_external_call;
+pub mod unchecked_l1_handler_from_address;
+pub mod unemitted_event;
+pub mod unprotected_privileged_operation;
+pub mod unused_storage_field;
+
+pub const ALLOWED: [&str; 7] = [
+    storage_write_after_external_call::LINT_NAME,
+    unprotected_privileged_operation::LINT_NAME,
+    unchecked_l1_handler_from_address::LINT_NAME,
+    block_info_dependence::LINT_NAME,
+    unemitted_event::LINT_NAME,
+    unused_storage_field::LINT_NAME,
+    missing_zero_address_check::LINT_NAME,
+];
+
+const CALL_CONTRACT_SYSCALL: &str = "core::starknet::syscalls::call_contract_syscall";
+const STORAGE_WRITE_SYSCALL: &str = "core::starknet::syscalls::storage_write_syscall";
+const STORAGE_MODULE: &str = "core::starknet::storage::";
+const CONTRACT_ATTR: &str = "starknet::contract";
+const CONSTRUCTOR_ATTR: &str = "constructor";
+const L1_HANDLER_ATTR: &str = "l1_handler";
+const STORAGE_ATTR: &str = "storage";
+const SUBSTORAGE_ATTR: &str = "substorage";
+
+/// Gets the syntax of the module if it's a `#[starknet::contract]` module.
+pub(crate) fn contract_module_ast(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+) -> Option<ast::ItemModule> {
+    let ModuleId::Submodule(submodule_id) = module_id else {
+        return None;
+    };
+    let module_ast = submodule_id.stable_ptr(db.upcast()).lookup(db.upcast());
+    module_ast
+        .has_attr(db.upcast(), CONTRACT_ATTR)
+        .then_some(module_ast)
+}
+
+/// Checks if the function is an entry point of a contract, i.e. an `#[external(v0)]` function or a
+/// function of an `#[abi(embed_v0)]` impl.
+pub(crate) fn is_entry_point(db: &dyn SemanticGroup, func_id: FunctionWithBodyId) -> bool {
+    let syntax_db = db.upcast();
+    match func_id {
+        FunctionWithBodyId::Free(free_function_id) => free_function_id
+            .stable_ptr(db.upcast())
+            .lookup(syntax_db)
+            .has_attr(syntax_db, "external"),
+        FunctionWithBodyId::Impl(impl_function_id) => {
+            let impl_def = impl_function_id
+                .impl_def_id(db.upcast())
+                .stable_ptr(db.upcast())
+                .lookup(syntax_db);
+            impl_function_id
+                .stable_ptr(db.upcast())
+                .lookup(syntax_db)
+                .has_attr(syntax_db, "external")
+                || impl_def.has_attr(syntax_db, "external")
+                || impl_def.query_attr(syntax_db, "abi").iter().any(|attr| {
+                    attr.arguments(syntax_db)
+                        .as_syntax_node()
+                        .get_text_without_trivia(syntax_db)
+                        == "(embed_v0)"
+                })
+        }
+        FunctionWithBodyId::Trait(_) => false,
+    }
+}
+
+/// Checks if the signature of the function is part of the ABI of a contract, i.e. if it's an entry
+/// point, the constructor or an L1 handler.
+pub(crate) fn is_abi_function(db: &dyn SemanticGroup, func_id: FunctionWithBodyId) -> bool {
+    if is_entry_point(db, func_id) {
+        return true;
+    }
+    let FunctionWithBodyId::Free(free_function_id) = func_id else {
+        return false;
+    };
+    let function = free_function_id.stable_ptr(db.upcast()).lookup(db.upcast());
+    function.has_attr(db.upcast(), CONSTRUCTOR_ATTR)
+        || function.has_attr(db.upcast(), L1_HANDLER_ATTR)
+}
+
+/// Gets the items of the contract module as written by the user, i.e. before the expansion of the
+/// `#[storage]` struct and of the `#[event]` enum.
+fn contract_items(db: &dyn SyntaxGroup, contract: &ast::ItemModule) -> Vec<ast::ModuleItem> {
+    match contract.body(db) {
+        ast::MaybeModuleBody::Some(body) => body.items(db).elements(db),
+        ast::MaybeModuleBody::None(_) => Vec::new(),
+    }
+}
+
+/// Gets the `#[storage]` struct of the contract, as written by the user.
+fn storage_struct(db: &dyn SyntaxGroup, contract: &ast::ItemModule) -> Option<ast::ItemStruct> {
+    contract_items(db, contract)
+        .into_iter()
+        .find_map(|item| match item {
+            ast::ModuleItem::Struct(item_struct) if item_struct.has_attr(db, STORAGE_ATTR) => {
+                Some(item_struct)
+            }
+            _ => None,
+        })
+}
+
+/// Checks if the function call calls another contract, either through a dispatcher generated by
+/// `#[starknet::interface]` or directly with `call_contract_syscall`.
+pub(crate) fn is_external_call(db: &dyn SemanticGroup, expr_func: &ExprFunctionCall) -> bool {
+    if expr_func.function.full_name(db) == CALL_CONTRACT_SYSCALL {
+        return true;
+    }
+    // The trait function path ends with `<TraitName>::<function_name>`.
+    function_trait_name_from_fn_id(db, &expr_func.function)
+        .rsplit("::")
+        .nth(1)
+        .is_some_and(|trait_name| trait_name.ends_with("DispatcherTrait"))
+}
+
+/// Checks if the function call writes to the contract storage, either through the storage access
+/// traits (e.g. `self.value.write(x)`) or directly with `storage_write_syscall`.
+pub(crate) fn is_storage_write(db: &dyn SemanticGroup, expr_func: &ExprFunctionCall) -> bool {
+    let trait_function = function_trait_name_from_fn_id(db, &expr_func.function);
+    (trait_function.starts_with(STORAGE_MODULE) && trait_function.ends_with("::write"))
+        || expr_func.function.full_name(db) == STORAGE_WRITE_SYSCALL
+}
+
+/// Checks if the full name of a function matches a name or a full path, e.g. `assert_only_owner`
+/// or `my_package::access::assert_only_owner`.
+pub(crate) fn is_function_named(function_name: &str, name_or_path: &str) -> bool {
+    function_name == name_or_path
+        || function_name
+            .strip_suffix(name_or_path)
+            .is_some_and(|path| path.ends_with("::"))
+}
+
+/// Checks if the function call reads the contract storage through the storage access traits, e.g.
+/// `self.value.read()`.
+pub(crate) fn is_storage_read(db: &dyn SemanticGroup, expr_func: &ExprFunctionCall) -> bool {
+    let trait_function = function_trait_name_from_fn_id(db, &expr_func.function);
+    trait_function.starts_with(STORAGE_MODULE) && trait_function.ends_with("::read")
+}
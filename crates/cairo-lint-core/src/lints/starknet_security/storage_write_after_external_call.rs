@@ -0,0 +1,55 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr};
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use super::{is_external_call, is_storage_write};
+use crate::analysis::execution_order::ExecutionOrder;
+
+pub const STORAGE_WRITE_AFTER_EXTERNAL_CALL: &str =
+    "Contract storage written after an external call. Consider updating the storage before \
+     calling other contracts (checks-effects-interactions pattern) to prevent reentrancy.";
+
+pub(crate) const LINT_NAME: &str = "storage_write_after_external_call";
+
+/// Checks for storage writes that may happen after a call to another contract in the same function,
+/// e.g. a dispatcher call followed by `self.balance.write(x)`.
+///
+/// A write using the result of an external call as an argument is reported as well, as are the
+/// writes preceding an external call in a loop. Writes in another branch of an `if` or a `match`
+/// than the external call aren't reported.
+pub fn check_storage_write_after_external_call(
+    db: &dyn SemanticGroup,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let mut external_calls = Vec::new();
+    let mut storage_writes = Vec::new();
+    for (_expr_id, expr) in &arenas.exprs {
+        let Expr::FunctionCall(expr_func) = expr else {
+            continue;
+        };
+        if is_external_call(db, expr_func) {
+            external_calls.push(expr_func.stable_ptr.untyped());
+        } else if is_storage_write(db, expr_func) {
+            storage_writes.push(expr_func.stable_ptr.untyped());
+        }
+    }
+    if external_calls.is_empty() {
+        return;
+    }
+    let execution_order = ExecutionOrder::new(db, arenas);
+    for write in storage_writes {
+        if external_calls
+            .iter()
+            .any(|call| execution_order.may_run_before(db, *call, write))
+        {
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: write,
+                message: STORAGE_WRITE_AFTER_EXTERNAL_CALL.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use cairo_lang_defs::ids::{FunctionWithBodyId, ParamId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCallArg, ExprId, ExprVar, VarId};
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::TypedStablePtr;
+use if_chain::if_chain;
+
+use super::L1_HANDLER_ATTR;
+use crate::lints::{function_trait_name_from_fn_id, EQ, INTO, NE};
+
+pub const UNUSED_L1_HANDLER_FROM_ADDRESS: &str =
+    "The `from_address` of this L1 handler is unused, so any L1 contract can trigger it. Consider \
+     asserting that it's a trusted L1 contract.";
+pub const UNCHECKED_L1_HANDLER_FROM_ADDRESS: &str =
+    "The `from_address` of this L1 handler is never compared, so any L1 contract can trigger it. \
+     Consider asserting that it's a trusted L1 contract.";
+
+pub(crate) const LINT_NAME: &str = "unchecked_l1_handler_from_address";
+
+/// Checks for `#[l1_handler]` functions whose `from_address` parameter is unused or never part of a
+/// comparison, e.g. `assert(from_address == L1_BRIDGE, 'Unknown sender')`.
+pub fn check_unchecked_l1_handler_from_address(
+    db: &dyn SemanticGroup,
+    func_id: FunctionWithBodyId,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if_chain! {
+        if let FunctionWithBodyId::Free(free_function_id) = func_id;
+        if free_function_id
+            .stable_ptr(db.upcast())
+            .lookup(db.upcast())
+            .has_attr(db.upcast(), L1_HANDLER_ATTR);
+        if let Ok(signature) = db.function_with_body_signature(func_id);
+        // The L1 address of the sender is the parameter following the contract state.
+        if let Some(from_address) = signature.params.get(1);
+        then {
+            let is_used = arenas
+                .exprs
+                .iter()
+                .any(|(expr_id, _)| is_param(expr_id, from_address.id, arenas));
+            let is_compared = arenas.exprs.iter().any(|(_expr_id, expr)| match expr {
+                Expr::FunctionCall(expr_func) => {
+                    let func = function_trait_name_from_fn_id(db, &expr_func.function);
+                    [EQ, NE].contains(&func.as_str())
+                        && expr_func.args.iter().any(|arg| {
+                            matches!(arg, ExprFunctionCallArg::Value(expr_id)
+                                if is_from_address(db, *expr_id, from_address.id, arenas))
+                        })
+                }
+                _ => false,
+            });
+            let message = if !is_used {
+                UNUSED_L1_HANDLER_FROM_ADDRESS
+            } else if !is_compared {
+                UNCHECKED_L1_HANDLER_FROM_ADDRESS
+            } else {
+                return;
+            };
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: from_address.stable_ptr.untyped(),
+                message: message.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+/// Checks if the expression is the `from_address` parameter, possibly snapshotted or converted
+/// with `into`.
+fn is_from_address(
+    db: &dyn SemanticGroup,
+    expr_id: ExprId,
+    from_address: ParamId,
+    arenas: &Arenas,
+) -> bool {
+    match &arenas.exprs[expr_id] {
+        Expr::Snapshot(snapshot) => is_from_address(db, snapshot.inner, from_address, arenas),
+        Expr::Desnap(desnap) => is_from_address(db, desnap.inner, from_address, arenas),
+        Expr::FunctionCall(expr_func)
+            if function_trait_name_from_fn_id(db, &expr_func.function) == INTO =>
+        {
+            matches!(expr_func.args.as_slice(), [ExprFunctionCallArg::Value(value)]
+                if is_from_address(db, *value, from_address, arenas))
+        }
+        _ => is_param(expr_id, from_address, arenas),
+    }
+}
+
+fn is_param(expr_id: ExprId, param: ParamId, arenas: &Arenas) -> bool {
+    matches!(&arenas.exprs[expr_id],
+        Expr::Var(ExprVar { var: VarId::Param(id), .. }) if *id == param)
+}
@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, LanguageElementId, ModuleId, ModuleItemId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{ConcreteTypeId, Expr, ExprFunctionCallArg, TypeId, TypeLongId};
+use cairo_lang_syntax::node::ast;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use super::{contract_items, storage_struct, SUBSTORAGE_ATTR};
+use crate::lints::function_trait_name_from_fn_id;
+
+pub const UNEMITTED_EVENT: &str = "Event never emitted. Consider emitting it or removing it.";
+
+pub(crate) const LINT_NAME: &str = "unemitted_event";
+
+const EVENT_ATTR: &str = "event";
+const FLAT_ATTR: &str = "flat";
+const EMIT: &str = "core::starknet::event::EventEmitter::emit";
+
+/// Checks for variants of the `#[event]` enum of a contract that are never emitted by the
+/// functions of the contract.
+///
+/// A variant is emitted when its value is passed to `emit` (e.g. `self.emit(Transfer { .. })`) or
+/// when the event enum is built from it (e.g. `Event::Transfer(transfer)`). The events of the
+/// components, i.e. the `#[flat]` variants and the variants holding the `Event` enum of a
+/// component embedded with `#[substorage]`, are ignored as the components emit them.
+pub fn check_unemitted_event(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    contract: &ast::ItemModule,
+    functions: &[FunctionWithBodyId],
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let syntax_db = db.upcast();
+    let Some(event) = contract_items(syntax_db, contract)
+        .into_iter()
+        .find_map(|item| match item {
+            ast::ModuleItem::Enum(item_enum) if item_enum.has_attr(syntax_db, EVENT_ATTR) => {
+                Some(item_enum)
+            }
+            _ => None,
+        })
+    else {
+        return;
+    };
+    let Ok(Some(ModuleItemId::Enum(enum_id))) =
+        db.module_item_by_name(module_id, event.name(syntax_db).text(syntax_db))
+    else {
+        return;
+    };
+    let Ok(variants) = db.enum_variants(enum_id) else {
+        return;
+    };
+    let component_modules = component_modules(db, module_id, contract);
+    let mut emitted_types = HashSet::new();
+    let mut built_variants = HashSet::new();
+    for func_id in functions {
+        let Ok(function_body) = db.function_body(*func_id) else {
+            continue;
+        };
+        let arenas = &function_body.arenas;
+        for (_expr_id, expr) in &arenas.exprs {
+            match expr {
+                Expr::FunctionCall(expr_func)
+                    if function_trait_name_from_fn_id(db, &expr_func.function) == EMIT =>
+                {
+                    if let Some(ExprFunctionCallArg::Value(event_expr)) = expr_func.args.last() {
+                        emitted_types.insert(arenas.exprs[*event_expr].ty());
+                    }
+                }
+                Expr::EnumVariantCtor(enum_variant_ctor) => {
+                    built_variants.insert(enum_variant_ctor.variant.id);
+                }
+                _ => {}
+            }
+        }
+    }
+    for variant in event.variants(syntax_db).elements(syntax_db) {
+        if variant.has_attr(syntax_db, FLAT_ATTR) {
+            continue;
+        }
+        let Some(variant_id) = variants.get(&variant.name(syntax_db).text(syntax_db)) else {
+            continue;
+        };
+        let Ok(semantic_variant) = db.variant_semantic(enum_id, *variant_id) else {
+            continue;
+        };
+        if built_variants.contains(variant_id)
+            || emitted_types.contains(&semantic_variant.ty)
+            || is_component_event(db, semantic_variant.ty, &component_modules)
+        {
+            continue;
+        }
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: variant.stable_ptr().untyped(),
+            message: UNEMITTED_EVENT.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Gets the modules of the components of the contract, i.e. the modules defining the types of
+/// its `#[substorage]` fields (e.g. `OwnableComponent` for `OwnableComponent::Storage`).
+fn component_modules(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    contract: &ast::ItemModule,
+) -> Vec<ModuleId> {
+    let syntax_db = db.upcast();
+    let Some(storage) = storage_struct(syntax_db, contract) else {
+        return Vec::new();
+    };
+    let Ok(Some(ModuleItemId::Struct(struct_id))) =
+        db.module_item_by_name(module_id, storage.name(syntax_db).text(syntax_db))
+    else {
+        return Vec::new();
+    };
+    let Ok(members) = db.struct_members(struct_id) else {
+        return Vec::new();
+    };
+    storage
+        .members(syntax_db)
+        .elements(syntax_db)
+        .into_iter()
+        .filter(|member| member.has_attr(syntax_db, SUBSTORAGE_ATTR))
+        .filter_map(|member| {
+            let member = members.get(&member.name(syntax_db).text(syntax_db))?;
+            match db.lookup_intern_type(member.ty) {
+                TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+                    Some(concrete_struct_id.struct_id(db).parent_module(db.upcast()))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Checks if the type is the `Event` enum of one of the components of the contract, e.g.
+/// `OwnableComponent::Event`, which is emitted by the component itself.
+fn is_component_event(db: &dyn SemanticGroup, ty: TypeId, component_modules: &[ModuleId]) -> bool {
+    matches!(db.lookup_intern_type(ty), TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id))
+        if component_modules.contains(&concrete_enum_id.enum_id(db).parent_module(db.upcast())))
+}
@@ -0,0 +1,397 @@
+use std::collections::{HashMap, HashSet};
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, LocalVarId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::corelib::never_ty;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{
+    Arenas, Condition, Expr, ExprFunctionCall, ExprFunctionCallArg, ExprId, ExprVar,
+    LogicalOperator, Pattern, Statement, VarId,
+};
+use cairo_lang_syntax::node::ast::{self, BinaryOperator};
+use cairo_lang_syntax::node::helpers::GetIdentifier;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::TypedStablePtr;
+
+use super::{is_entry_point, is_function_named, is_storage_write};
+use crate::analysis::execution_order::ExecutionOrder;
+use crate::analysis::{contains, location, Location};
+use crate::lints::{function_trait_name_from_fn_id, BOOL_NOT, EQ, NE};
+
+pub const UNPROTECTED_PRIVILEGED_OPERATION: &str =
+    "Privileged operation reachable from an entry point without any check of the caller. \
+     Consider asserting the caller (e.g. with `get_caller_address`) or calling a guard function \
+     before it.";
+
+pub(crate) const LINT_NAME: &str = "unprotected_privileged_operation";
+
+const REPLACE_CLASS_SYSCALL: &str = "core::starknet::syscalls::replace_class_syscall";
+const DEPLOY_SYSCALL: &str = "core::starknet::syscalls::deploy_syscall";
+const GET_CALLER_ADDRESS: &str = "core::starknet::info::get_caller_address";
+const ASSERT: &str = "core::assert";
+/// Guard functions of the OpenZeppelin `Ownable` and `AccessControl` components, always considered
+/// as checks of the caller.
+const DEFAULT_GUARD_FUNCTIONS: [&str; 2] = ["assert_only_owner", "assert_only_role"];
+/// Storage fields holding the ownership of the contract, possibly prefixed by the name of their
+/// component (e.g. `Ownable_owner`).
+const OWNERSHIP_FIELDS: [&str; 3] = ["owner", "admin", "pending_owner"];
+
+/// Checks for entry points of a contract (`#[external(v0)]` functions and functions of
+/// `#[abi(embed_v0)]` impls) that reach `replace_class_syscall`, `deploy_syscall` or a write to an
+/// ownership field of the storage without checking the caller before.
+///
+/// The caller is checked by comparing the result of `get_caller_address` with the expected address,
+/// either asserted (with `assert`, `assert!` or an `if` panicking or returning when the comparison
+/// fails) or in the condition of an `if` whose branch where the comparison holds contains the
+/// operation, or by calling one of the guard functions, which are `assert_only_owner`,
+/// `assert_only_role` and the ones listed in `guard_functions`, by name or full path. The check
+/// must be done before the operation, outside of any branch or loop that the operation isn't in.
+pub fn check_unprotected_privileged_operation(
+    db: &dyn SemanticGroup,
+    func_id: FunctionWithBodyId,
+    guard_functions: &[String],
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if !is_entry_point(db, func_id) {
+        return;
+    }
+    let mut analyzed_functions = HashMap::from([(func_id, false)]);
+    if let Some(stable_ptr) =
+        unprotected_operation(db, func_id, guard_functions, &mut analyzed_functions)
+    {
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr,
+            message: UNPROTECTED_PRIVILEGED_OPERATION.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Finds the first privileged operation of the function, or the first call to a function reaching
+/// one, that isn't preceded by a check of the caller.
+///
+/// `analyzed_functions` memoizes whether the functions already analyzed reach an unprotected
+/// operation, functions still being analyzed (i.e. recursive calls) are considered as not reaching
+/// one.
+fn unprotected_operation(
+    db: &dyn SemanticGroup,
+    func_id: FunctionWithBodyId,
+    guard_functions: &[String],
+    analyzed_functions: &mut HashMap<FunctionWithBodyId, bool>,
+) -> Option<SyntaxStablePtrId> {
+    let function_body = db.function_body(func_id).ok()?;
+    let arenas = &function_body.arenas;
+    let caller_vars = caller_variables(db, arenas);
+    let mut guards = caller_checks(db, arenas, &caller_vars);
+    let mut operations = Vec::new();
+    for (_expr_id, expr) in &arenas.exprs {
+        let Expr::FunctionCall(expr_func) = expr else {
+            continue;
+        };
+        if is_guard_function(db, expr_func, guard_functions) {
+            guards.push(Guard::new(expr_func.stable_ptr.untyped()));
+        } else if is_privileged_operation(db, expr_func)
+            || reaches_unprotected_operation(db, expr_func, guard_functions, analyzed_functions)
+        {
+            operations.push(expr_func.stable_ptr.untyped());
+        }
+    }
+    let execution_order = ExecutionOrder::new(db, arenas);
+    operations
+        .into_iter()
+        .filter(|operation| {
+            !guards
+                .iter()
+                .any(|guard| guard.protects(db, &execution_order, *operation))
+        })
+        .min_by_key(|operation| location(db, *operation).1.start)
+}
+
+/// A check of the caller, protecting the operations always evaluated after it.
+struct Guard {
+    /// The comparison of the caller, or the call to the guard function.
+    stable_ptr: SyntaxStablePtrId,
+    /// The branch of the `if` where the comparison holds, if it only holds there.
+    held_in: Option<Location>,
+    /// The branch of the `if` taken when the comparison fails, which only holds after it.
+    failed_in: Option<Location>,
+}
+
+impl Guard {
+    fn new(stable_ptr: SyntaxStablePtrId) -> Self {
+        Self {
+            stable_ptr,
+            held_in: None,
+            failed_in: None,
+        }
+    }
+
+    fn protects(
+        &self,
+        db: &dyn SemanticGroup,
+        execution_order: &ExecutionOrder,
+        operation: SyntaxStablePtrId,
+    ) -> bool {
+        let operation_location = location(db, operation);
+        execution_order.always_runs_before(db, self.stable_ptr, operation)
+            && self
+                .held_in
+                .is_none_or(|branch| contains(branch, operation_location))
+            && self
+                .failed_in
+                .is_none_or(|branch| !contains(branch, operation_location))
+    }
+}
+
+fn reaches_unprotected_operation(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    guard_functions: &[String],
+    analyzed_functions: &mut HashMap<FunctionWithBodyId, bool>,
+) -> bool {
+    if expr_func.function.full_name(db).starts_with("core::") {
+        return false;
+    }
+    let Ok(Some(callee)) = expr_func.function.get_concrete(db).body(db) else {
+        return false;
+    };
+    let callee = callee.function_with_body_id(db);
+    if let Some(reaches) = analyzed_functions.get(&callee) {
+        return *reaches;
+    }
+    analyzed_functions.insert(callee, false);
+    let reaches = unprotected_operation(db, callee, guard_functions, analyzed_functions).is_some();
+    analyzed_functions.insert(callee, reaches);
+    reaches
+}
+
+fn is_privileged_operation(db: &dyn SemanticGroup, expr_func: &ExprFunctionCall) -> bool {
+    let function_name = expr_func.function.full_name(db);
+    function_name == REPLACE_CLASS_SYSCALL
+        || function_name == DEPLOY_SYSCALL
+        || (is_storage_write(db, expr_func) && writes_ownership_field(db, expr_func))
+}
+
+/// Checks if the storage write is done on an ownership field, e.g. `self.owner.write(new_owner)`.
+fn writes_ownership_field(db: &dyn SemanticGroup, expr_func: &ExprFunctionCall) -> bool {
+    let syntax_db = db.upcast();
+    let ast::Expr::Binary(write_call) = expr_func.stable_ptr.lookup(syntax_db) else {
+        return false;
+    };
+    let field_path = match write_call.lhs(syntax_db) {
+        ast::Expr::Binary(member_access)
+            if matches!(member_access.op(syntax_db), BinaryOperator::Dot(_)) =>
+        {
+            member_access.rhs(syntax_db)
+        }
+        lhs => lhs,
+    };
+    let ast::Expr::Path(field_path) = field_path else {
+        return false;
+    };
+    let Some(field) = field_path
+        .elements(syntax_db)
+        .last()
+        .map(|segment| segment.identifier(syntax_db))
+    else {
+        return false;
+    };
+    let field = match field.split_once('_') {
+        Some((component, component_field)) if component.starts_with(char::is_uppercase) => {
+            component_field
+        }
+        _ => field.as_str(),
+    };
+    OWNERSHIP_FIELDS.contains(&field)
+}
+
+fn is_guard_function(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    guard_functions: &[String],
+) -> bool {
+    let function_name = expr_func.function.full_name(db);
+    DEFAULT_GUARD_FUNCTIONS
+        .into_iter()
+        .chain(guard_functions.iter().map(String::as_str))
+        .any(|guard| is_function_named(&function_name, guard))
+}
+
+/// Gets the checks of the caller done by the function: the `assert` of a comparison of the caller,
+/// and the `if` expressions comparing it in their condition (including the ones generated by
+/// `assert!`).
+fn caller_checks(
+    db: &dyn SemanticGroup,
+    arenas: &Arenas,
+    caller_vars: &HashSet<LocalVarId>,
+) -> Vec<Guard> {
+    let mut guards = Vec::new();
+    let expr_location =
+        |expr_id: ExprId| location(db, arenas.exprs[expr_id].stable_ptr().untyped());
+    for (_expr_id, expr) in &arenas.exprs {
+        match expr {
+            Expr::If(expr_if) => {
+                let Condition::BoolExpr(condition) = expr_if.condition else {
+                    continue;
+                };
+                if let Some(check) = caller_check(db, condition, true, arenas, caller_vars) {
+                    guards.push(Guard {
+                        held_in: Some(expr_location(expr_if.if_block)),
+                        ..Guard::new(check)
+                    });
+                    if let Some(else_block) = expr_if.else_block {
+                        if always_exits(db, else_block, arenas) {
+                            guards.push(Guard {
+                                failed_in: Some(expr_location(else_block)),
+                                ..Guard::new(check)
+                            });
+                        }
+                    }
+                }
+                if let Some(check) = caller_check(db, condition, false, arenas, caller_vars) {
+                    if let Some(else_block) = expr_if.else_block {
+                        guards.push(Guard {
+                            held_in: Some(expr_location(else_block)),
+                            ..Guard::new(check)
+                        });
+                    }
+                    if always_exits(db, expr_if.if_block, arenas) {
+                        guards.push(Guard {
+                            failed_in: Some(expr_location(expr_if.if_block)),
+                            ..Guard::new(check)
+                        });
+                    }
+                }
+            }
+            Expr::FunctionCall(expr_func) if expr_func.function.full_name(db) == ASSERT => {
+                if let Some(ExprFunctionCallArg::Value(condition)) = expr_func.args.first() {
+                    if let Some(check) = caller_check(db, *condition, true, arenas, caller_vars) {
+                        guards.push(Guard::new(check));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    guards
+}
+
+/// Finds the comparison of the caller address that holds when the condition evaluates to `value`,
+/// e.g. `get_caller_address() == owner` when `get_caller_address() == owner && amount > 0` is true
+/// or when `get_caller_address() != owner` is false.
+fn caller_check(
+    db: &dyn SemanticGroup,
+    condition: ExprId,
+    value: bool,
+    arenas: &Arenas,
+    caller_vars: &HashSet<LocalVarId>,
+) -> Option<SyntaxStablePtrId> {
+    match &arenas.exprs[condition] {
+        Expr::LogicalOperator(expr_logical) => match (&expr_logical.op, value) {
+            (LogicalOperator::AndAnd, true) | (LogicalOperator::OrOr, false) => {
+                caller_check(db, expr_logical.lhs, value, arenas, caller_vars)
+                    .or_else(|| caller_check(db, expr_logical.rhs, value, arenas, caller_vars))
+            }
+            _ => None,
+        },
+        Expr::FunctionCall(expr_func) => {
+            let function_name = function_trait_name_from_fn_id(db, &expr_func.function);
+            if function_name == BOOL_NOT {
+                let Some(ExprFunctionCallArg::Value(operand)) = expr_func.args.first() else {
+                    return None;
+                };
+                caller_check(db, *operand, !value, arenas, caller_vars)
+            } else if (function_name == EQ && value) || (function_name == NE && !value) {
+                is_caller_comparison(db, expr_func, arenas, caller_vars)
+                    .then_some(expr_func.stable_ptr.untyped())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Checks if the comparison is between the caller address and another value, e.g.
+/// `get_caller_address() == owner`.
+fn is_caller_comparison(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    arenas: &Arenas,
+    caller_vars: &HashSet<LocalVarId>,
+) -> bool {
+    expr_func
+        .args
+        .iter()
+        .filter(|arg| {
+            matches!(arg, ExprFunctionCallArg::Value(expr_id)
+                if is_caller(db, *expr_id, arenas, caller_vars))
+        })
+        .count()
+        == 1
+}
+
+/// Checks if the evaluation of the expression always panics or returns from the function, e.g.
+/// `{ panic!("Caller is not the owner") }`.
+fn always_exits(db: &dyn SemanticGroup, expr_id: ExprId, arenas: &Arenas) -> bool {
+    let expr = &arenas.exprs[expr_id];
+    if expr.ty() == never_ty(db) {
+        return true;
+    }
+    let Expr::Block(expr_block) = expr else {
+        return false;
+    };
+    expr_block
+        .statements
+        .iter()
+        .any(|statement_id| match &arenas.statements[*statement_id] {
+            Statement::Return(_) => true,
+            Statement::Expr(statement_expr) => always_exits(db, statement_expr.expr, arenas),
+            _ => false,
+        })
+        || expr_block
+            .tail
+            .is_some_and(|tail| always_exits(db, tail, arenas))
+}
+
+/// Checks if the expression is the caller address, either a call to `get_caller_address` or a
+/// variable bound to it.
+fn is_caller(
+    db: &dyn SemanticGroup,
+    expr_id: ExprId,
+    arenas: &Arenas,
+    caller_vars: &HashSet<LocalVarId>,
+) -> bool {
+    match &arenas.exprs[expr_id] {
+        Expr::Snapshot(snapshot) => is_caller(db, snapshot.inner, arenas, caller_vars),
+        Expr::Desnap(desnap) => is_caller(db, desnap.inner, arenas, caller_vars),
+        Expr::FunctionCall(expr_func) => expr_func.function.full_name(db) == GET_CALLER_ADDRESS,
+        Expr::Var(ExprVar {
+            var: VarId::Local(local_var),
+            ..
+        }) => caller_vars.contains(local_var),
+        _ => false,
+    }
+}
+
+/// Gets the variables bound to the caller address, e.g. `let caller = get_caller_address();`.
+fn caller_variables(db: &dyn SemanticGroup, arenas: &Arenas) -> HashSet<LocalVarId> {
+    arenas
+        .statements
+        .iter()
+        .filter_map(|(_statement_id, statement)| {
+            let Statement::Let(statement_let) = statement else {
+                return None;
+            };
+            match &arenas.patterns[statement_let.pattern] {
+                Pattern::Variable(pattern)
+                    if is_caller(db, statement_let.expr, arenas, &HashSet::new()) =>
+                {
+                    Some(pattern.var.id)
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, NamedLanguageElementId};
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::Expr;
+use cairo_lang_syntax::node::ast::{self, BinaryOperator};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::{GetIdentifier, QueryAttrs};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+use super::{storage_struct, SUBSTORAGE_ATTR};
+
+pub const WRITE_ONLY_STORAGE_FIELD: &str =
+    "Storage field written but never read. Consider removing it, as writing it only wastes gas.";
+pub const UNUSED_STORAGE_FIELD: &str = "Storage field never used. Consider removing it.";
+
+pub(crate) const LINT_NAME: &str = "unused_storage_field";
+
+/// Structs generated by `#[starknet::contract]` to access the members of the storage.
+const STORAGE_BASE_STRUCTS: [&str; 2] = ["ContractStorageBase", "ContractStorageBaseMut"];
+
+/// Checks for fields of the `#[storage]` struct of a contract that are never used, or only written,
+/// by the functions of the contract.
+///
+/// Any access to a field that isn't a `write` (e.g. `self.values.len()`) counts as a read, and
+/// `#[substorage]` fields are ignored as they're used by their component.
+pub fn check_unused_storage_field(
+    db: &dyn SemanticGroup,
+    contract: &ast::ItemModule,
+    functions: &[FunctionWithBodyId],
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let syntax_db = db.upcast();
+    let Some(storage) = storage_struct(syntax_db, contract) else {
+        return;
+    };
+    let mut read_fields = HashSet::new();
+    let mut written_fields = HashSet::new();
+    for func_id in functions {
+        let Ok(function_body) = db.function_body(*func_id) else {
+            continue;
+        };
+        for (_expr_id, expr) in &function_body.arenas.exprs {
+            let Expr::MemberAccess(member_access) = expr else {
+                continue;
+            };
+            let struct_name = member_access
+                .concrete_struct_id
+                .struct_id(db)
+                .name(db.upcast());
+            if !STORAGE_BASE_STRUCTS.contains(&struct_name.as_str()) {
+                continue;
+            }
+            let field = member_access.member.name(db.upcast());
+            let access = member_access.stable_ptr.lookup(syntax_db).as_syntax_node();
+            if is_write(syntax_db, access) {
+                written_fields.insert(field);
+            } else {
+                read_fields.insert(field);
+            }
+        }
+    }
+    for member in storage.members(syntax_db).elements(syntax_db) {
+        if member.has_attr(syntax_db, SUBSTORAGE_ATTR) {
+            continue;
+        }
+        let field = member.name(syntax_db).text(syntax_db);
+        let message = if read_fields.contains(&field) {
+            continue;
+        } else if written_fields.contains(&field) {
+            WRITE_ONLY_STORAGE_FIELD
+        } else {
+            UNUSED_STORAGE_FIELD
+        };
+        diagnostics.push(PluginDiagnostic {
+            stable_ptr: member.stable_ptr().untyped(),
+            message: message.to_string(),
+            severity: Severity::Warning,
+        });
+    }
+}
+
+/// Checks if the access to the storage field ends with a `write`, following the chain of method
+/// calls made on it, e.g. `self.balances.entry(account).write(amount)`.
+fn is_write(db: &dyn SyntaxGroup, access: SyntaxNode) -> bool {
+    let mut node = access;
+    while let Some(parent) = node.parent() {
+        if parent.kind(db) != SyntaxKind::ExprBinary {
+            return false;
+        }
+        let binary = ast::ExprBinary::from_syntax_node(db, parent.clone());
+        if !matches!(binary.op(db), BinaryOperator::Dot(_))
+            || binary.lhs(db).as_syntax_node() != node
+        {
+            return false;
+        }
+        if let ast::Expr::FunctionCall(method_call) = binary.rhs(db) {
+            match method_call
+                .path(db)
+                .elements(db)
+                .last()
+                .map(|segment| segment.identifier(db))
+                .as_deref()
+            {
+                Some("write") => return true,
+                Some("read") => return false,
+                _ => {}
+            }
+        }
+        node = parent;
+    }
+    false
+}
@@ -0,0 +1,71 @@
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_semantic::corelib::core_felt252_ty;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Arenas, Expr, ExprFunctionCall, ExprFunctionCallArg, TypeId};
+use cairo_lang_syntax::node::TypedStablePtr;
+use if_chain::if_chain;
+use num_bigint::BigInt;
+
+use super::{function_trait_name_from_fn_id, int_range, TRY_INTO, UNWRAP};
+
+pub const TRY_INTO_UNWRAP: &str =
+    "`try_into().unwrap()` panics with an opaque message if the value doesn't fit in the target \
+     type. Consider using `.expect()` with a meaningful message or handling the error explicitly.";
+pub const LOSSLESS_TRY_INTO_UNWRAP: &str = "This conversion can't fail as the source type fits in \
+                                            the target type. Consider using `.into()` instead.";
+
+pub const ALLOWED: [&str; 1] = [LINT_NAME];
+pub(crate) const LINT_NAME: &str = "try_into_unwrap";
+
+/// Checks for `x.try_into().unwrap()` between integer or felt types.
+pub fn check_try_into_unwrap(
+    db: &dyn SemanticGroup,
+    expr_func: &ExprFunctionCall,
+    arenas: &Arenas,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    if_chain! {
+        if function_trait_name_from_fn_id(db, &expr_func.function) == UNWRAP;
+        if let [ExprFunctionCallArg::Value(option)] = expr_func.args.as_slice();
+        if let Expr::FunctionCall(try_into_call) = &arenas.exprs[*option];
+        if function_trait_name_from_fn_id(db, &try_into_call.function) == TRY_INTO;
+        if let [ExprFunctionCallArg::Value(value)] = try_into_call.args.as_slice();
+        let source_ty = arenas.exprs[*value].ty();
+        if is_numeric(db, source_ty) && is_numeric(db, expr_func.ty);
+        then {
+            let message = if fits_in(db, source_ty, expr_func.ty) {
+                LOSSLESS_TRY_INTO_UNWRAP
+            } else {
+                TRY_INTO_UNWRAP
+            };
+            diagnostics.push(PluginDiagnostic {
+                stable_ptr: expr_func.stable_ptr.untyped(),
+                message: message.to_string(),
+                severity: Severity::Warning,
+            });
+        }
+    }
+}
+
+fn is_numeric(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    ty == core_felt252_ty(db) || int_range(db, ty).is_some()
+}
+
+/// Checks if every value of the source type fits in the target type and the corelib implements
+/// `Into` between them.
+fn fits_in(db: &dyn SemanticGroup, source_ty: TypeId, target_ty: TypeId) -> bool {
+    let Some((source_min, source_max)) = int_range(db, source_ty) else {
+        return false;
+    };
+    if target_ty == core_felt252_ty(db) {
+        return source_max < BigInt::from(1) << 128;
+    }
+    let Some((target_min, target_max)) = int_range(db, target_ty) else {
+        return false;
+    };
+    // There's no `Into` between unsigned and signed integers.
+    (source_min < BigInt::from(0)) == (target_min < BigInt::from(0))
+        && source_min >= target_min
+        && source_max <= target_max
+}
@@ -1,35 +1,184 @@
-use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleId, ModuleItemId};
-use cairo_lang_defs::plugin::PluginDiagnostic;
+use std::path::Path;
+use std::sync::Arc;
+
+use cairo_lang_defs::ids::{
+    FunctionWithBodyId, LanguageElementId, ModuleId, ModuleItemId, StructId,
+};
+use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginResult};
 use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::items::attribute::SemanticQueryAttrs;
 use cairo_lang_semantic::plugin::{AnalyzerPlugin, PluginSuite};
-use cairo_lang_semantic::{Expr, Statement};
-use cairo_lang_syntax::node::ast::Expr as AstExpr;
+use cairo_lang_semantic::{ConcreteTypeId, Expr, Statement, TypeId, TypeLongId};
+use cairo_lang_syntax::node::ast::{self, Expr as AstExpr, ModuleItem};
+use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
 
+use crate::config::LintConfig;
+use crate::diagnostics::{AttachedDetails, LintDetails};
 use crate::lints::ifs::{self, *};
 use crate::lints::loops::{loop_for_while, loop_match_pop_front};
 use crate::lints::manual::{self, *};
+use crate::lints::starknet_security::{
+    block_info_dependence, contract_module_ast, missing_zero_address_check,
+    storage_write_after_external_call, unchecked_l1_handler_from_address, unemitted_event,
+    unprotected_privileged_operation, unused_storage_field,
+};
 use crate::lints::{
-    bitwise_for_parity_check, bool_comparison, breaks, double_comparison, double_parens,
-    duplicate_underscore_args, eq_op, erasing_op, int_op_one, loops, panic, performance,
-    single_match,
+    absurd_extreme_comparisons, array_init_then_append, array_param_only_read,
+    bitwise_for_parity_check, bool_comparison, bool_simplification, breaks, div_before_mul,
+    double_comparison, double_parens, duplicate_underscore_args, eq_op, erasing_op,
+    felt252_arithmetic, identity_op, int_op_one, is_macro_generated, loops, panic, performance,
+    redundant_clone, returns, single_match, starknet_security, try_into_unwrap,
 };
+use crate::suppression::{self, suppress_diagnostics};
 
 pub fn cairo_lint_plugin_suite() -> PluginSuite {
+    cairo_lint_plugin_suite_with_config(LintConfig::default())
+}
+
+/// Same as [`cairo_lint_plugin_suite`] but the level of the lints is overridden according to
+/// `config`.
+pub fn cairo_lint_plugin_suite_with_config(config: LintConfig) -> PluginSuite {
+    cairo_lint_plugin_suite_with_details(config, LintDetails::default())
+}
+
+/// Same as [`cairo_lint_plugin_suite_with_config`] but the details attached by the lints to their
+/// diagnostics are collected in `details`, to be given to the renderer of the diagnostics.
+pub fn cairo_lint_plugin_suite_with_details(
+    config: LintConfig,
+    details: LintDetails,
+) -> PluginSuite {
     let mut suite = PluginSuite::default();
-    suite.add_analyzer_plugin::<CairoLint>();
+    suite.add_analyzer_plugin_ex(Arc::new(CairoLint::new(config, details)));
+    suite.add_plugin::<CairoLintAttributes>();
     suite
 }
+
+/// Declares the attributes used by cairo-lint so the compiler doesn't reject them.
+#[derive(Debug, Default)]
+pub struct CairoLintAttributes;
+
+impl MacroPlugin for CairoLintAttributes {
+    fn generate_code(
+        &self,
+        _db: &dyn SyntaxGroup,
+        _item_ast: ModuleItem,
+        _metadata: &MacroPluginMetadata<'_>,
+    ) -> PluginResult {
+        PluginResult::default()
+    }
+
+    fn declared_attributes(&self) -> Vec<String> {
+        vec![suppression::EXPECT_ATTR.to_string()]
+    }
+}
+
 #[derive(Debug, Default)]
-pub struct CairoLint;
+pub struct CairoLint {
+    config: LintConfig,
+    details: LintDetails,
+}
+
+impl CairoLint {
+    pub fn new(config: LintConfig, details: LintDetails) -> Self {
+        Self { config, details }
+    }
+
+    /// Gets the diagnostics of the module, collecting the details attached to them in `details`.
+    fn lint_module(
+        &self,
+        db: &dyn SemanticGroup,
+        module_id: ModuleId,
+        details: &mut Vec<AttachedDetails>,
+    ) -> Vec<PluginDiagnostic> {
+        let mut diags = Vec::new();
+        let mut item_nodes = Vec::new();
+        let mut function_ids = Vec::new();
+        let syntax_db = db.upcast();
+        let Ok(items) = db.module_items(module_id) else {
+            return diags;
+        };
+        for item in &*items {
+            if is_generated_item(db, item) {
+                continue;
+            }
+            let item_node = match item {
+                ModuleItemId::Constant(constant_id) => constant_id
+                    .stable_ptr(db.upcast())
+                    .lookup(syntax_db)
+                    .as_syntax_node(),
+                ModuleItemId::FreeFunction(free_function_id) => {
+                    let func_id = FunctionWithBodyId::Free(*free_function_id);
+                    check_function(db, func_id, &self.config, &mut diags, details);
+                    function_ids.push(func_id);
+                    free_function_id
+                        .stable_ptr(db.upcast())
+                        .lookup(syntax_db)
+                        .as_syntax_node()
+                }
+                ModuleItemId::Impl(impl_id) => {
+                    let impl_functions = db.impl_functions(*impl_id);
+                    let Ok(functions) = impl_functions else {
+                        continue;
+                    };
+                    for (_fn_name, fn_id) in functions.iter() {
+                        let func_id = FunctionWithBodyId::Impl(*fn_id);
+                        check_function(db, func_id, &self.config, &mut diags, details);
+                        function_ids.push(func_id);
+                    }
+                    impl_id
+                        .stable_ptr(db.upcast())
+                        .lookup(syntax_db)
+                        .as_syntax_node()
+                }
+                _ => continue,
+            };
+            item_nodes.push(item_node.clone());
+
+            for node in item_node.descendants(syntax_db) {
+                match node.kind(syntax_db) {
+                    SyntaxKind::ExprParenthesized => double_parens::check_double_parens(
+                        db.upcast(),
+                        &AstExpr::from_syntax_node(db.upcast(), node.clone()),
+                        &mut diags,
+                    ),
+                    SyntaxKind::ExprIf => {}
+                    SyntaxKind::ExprMatch => {}
+                    _ => continue,
+                }
+            }
+        }
+        if let Some(contract) = contract_module_ast(db, module_id) {
+            check_contract(db, module_id, &contract, &function_ids, &mut diags);
+        }
+        let module_path = module_id.full_path(db.upcast());
+        suppress_diagnostics(
+            syntax_db,
+            &item_nodes,
+            &self.declared_allows(),
+            self.config.unused_allow,
+            diags,
+        )
+        .into_iter()
+        .filter_map(|diag| {
+            let Some(lint_name) = lint_name_from_message(&diag.message) else {
+                return Some(diag);
+            };
+            let file_path = diag.stable_ptr.file_id(syntax_db).full_path(db.upcast());
+            self.config
+                .apply_level(lint_name, Path::new(&file_path), &module_path, diag)
+        })
+        .collect()
+    }
+}
 
 #[derive(Debug, PartialEq)]
 pub enum CairoLintKind {
     DestructMatch,
     MatchForEquality,
-    DoubleComparison,
+    SimplifiableComparison,
+    RedundantComparison,
+    ContradictoryComparison,
     DoubleParens,
     EquatableIfLet,
     BreakUnit,
@@ -39,6 +188,13 @@ pub enum CairoLintKind {
     DuplicateUnderscoreArgs,
     LoopMatchPopFront,
     ManualUnwrapOrDefault,
+    ManualUnwrapOr,
+    ManualUnwrapOrElse,
+    ManualMap,
+    ManualMapErr,
+    ManualAndThen,
+    ManualFilter,
+    ManualFlatten,
     BitwiseForParityCheck,
     LoopForWhile,
     Unknown,
@@ -59,6 +215,129 @@ pub enum CairoLintKind {
     IntLePlusOne,
     IntLeMinOne,
     ImposibleComparison,
+    EqOp,
+    InefficientWhileComp,
+    ArrayInitThenAppend,
+    Felt252Comparison,
+    Felt252Division,
+    Felt252SubUnderflow,
+    DivBeforeMul,
+    AbsurdExtremeComparison,
+    TryIntoUnwrap,
+    LosslessTryIntoUnwrap,
+    IdentityOp,
+    NeedlessReturn,
+    LetAndReturn,
+    NeedlessBool,
+    NonminimalBool,
+    StorageWriteAfterExternalCall,
+    UnprotectedPrivilegedOperation,
+    UncheckedL1HandlerFromAddress,
+    StorageReadInLoop,
+    BlockInfoDependence,
+    UnemittedEvent,
+    UnusedStorageField,
+    MissingZeroAddressCheck,
+    ArrayParamOnlyRead,
+    RedundantClone,
+    UnusedAllow,
+    UnfulfilledLintExpectation,
+}
+
+impl CairoLintKind {
+    /// Gets the name of the lint, as used in `#[allow(...)]`, or `None` for the diagnostics that
+    /// can't be suppressed.
+    pub fn lint_name(&self) -> Option<&'static str> {
+        let lint_name = match self {
+            CairoLintKind::DestructMatch | CairoLintKind::MatchForEquality => {
+                single_match::LINT_NAME
+            }
+            CairoLintKind::DoubleParens => double_parens::LINT_NAME,
+            CairoLintKind::SimplifiableComparison => {
+                double_comparison::simplifiable_comparison::LINT_NAME
+            }
+            CairoLintKind::RedundantComparison => {
+                double_comparison::redundant_comaprison::LINT_NAME
+            }
+            CairoLintKind::ContradictoryComparison => {
+                double_comparison::contradictory_comparison::LINT_NAME
+            }
+            CairoLintKind::ImposibleComparison => {
+                double_comparison::impossible_comparison::LINT_NAME
+            }
+            CairoLintKind::BreakUnit => breaks::LINT_NAME,
+            CairoLintKind::EquatableIfLet => equatable_if_let::LINT_NAME,
+            CairoLintKind::BoolComparison => bool_comparison::LINT_NAME,
+            CairoLintKind::CollapsibleIfElse => collapsible_if_else::LINT_NAME,
+            CairoLintKind::DuplicateUnderscoreArgs => duplicate_underscore_args::LINT_NAME,
+            CairoLintKind::CollapsibleIf => collapsible_if::LINT_NAME,
+            CairoLintKind::LoopMatchPopFront => loop_match_pop_front::LINT_NAME,
+            CairoLintKind::ManualUnwrapOrDefault => manual_unwrap_or_default::LINT_NAME,
+            CairoLintKind::ManualUnwrapOr => manual_unwrap_or::or::LINT_NAME,
+            CairoLintKind::ManualUnwrapOrElse => manual_unwrap_or::or_else::LINT_NAME,
+            CairoLintKind::ManualMap => manual_map::LINT_NAME,
+            CairoLintKind::ManualMapErr => manual_map_err::LINT_NAME,
+            CairoLintKind::ManualAndThen => manual_and_then::LINT_NAME,
+            CairoLintKind::ManualFilter => manual_filter::LINT_NAME,
+            CairoLintKind::ManualFlatten => manual_flatten::LINT_NAME,
+            CairoLintKind::Panic => panic::LINT_NAME,
+            CairoLintKind::LoopForWhile => loop_for_while::LINT_NAME,
+            CairoLintKind::ErasingOperation => erasing_op::LINT_NAME,
+            CairoLintKind::ManualOkOr => manual_ok_or::LINT_NAME,
+            CairoLintKind::ManualOk => manual_ok::LINT_NAME,
+            CairoLintKind::ManualErr => manual_err::LINT_NAME,
+            CairoLintKind::BitwiseForParityCheck => bitwise_for_parity_check::LINT_NAME,
+            CairoLintKind::ManualIsSome => manual_is::some::LINT_NAME,
+            CairoLintKind::ManualIsNone => manual_is::none::LINT_NAME,
+            CairoLintKind::ManualIsOk => manual_is::ok::LINT_NAME,
+            CairoLintKind::ManualIsErr => manual_is::err::LINT_NAME,
+            CairoLintKind::ManualExpect => manual_expect::LINT_NAME,
+            CairoLintKind::DuplicateIfCondition => ifs_same_cond::LINT_NAME,
+            CairoLintKind::ManualExpectErr => manual_expect_err::LINT_NAME,
+            CairoLintKind::IntGePlusOne
+            | CairoLintKind::IntGeMinOne
+            | CairoLintKind::IntLePlusOne
+            | CairoLintKind::IntLeMinOne => int_op_one::LINT_NAME,
+            CairoLintKind::EqOp => eq_op::LINT_NAME,
+            CairoLintKind::InefficientWhileComp => performance::inefficient_while_comp::LINT_NAME,
+            CairoLintKind::StorageReadInLoop => performance::storage_read_in_loop::LINT_NAME,
+            CairoLintKind::ArrayInitThenAppend => array_init_then_append::LINT_NAME,
+            CairoLintKind::Felt252Comparison => felt252_arithmetic::felt252_comparison::LINT_NAME,
+            CairoLintKind::Felt252Division => felt252_arithmetic::felt252_division::LINT_NAME,
+            CairoLintKind::Felt252SubUnderflow => {
+                felt252_arithmetic::felt252_sub_underflow::LINT_NAME
+            }
+            CairoLintKind::DivBeforeMul => div_before_mul::LINT_NAME,
+            CairoLintKind::AbsurdExtremeComparison => absurd_extreme_comparisons::LINT_NAME,
+            CairoLintKind::TryIntoUnwrap | CairoLintKind::LosslessTryIntoUnwrap => {
+                try_into_unwrap::LINT_NAME
+            }
+            CairoLintKind::IdentityOp => identity_op::LINT_NAME,
+            CairoLintKind::NeedlessReturn => returns::needless_return::LINT_NAME,
+            CairoLintKind::LetAndReturn => returns::let_and_return::LINT_NAME,
+            CairoLintKind::NeedlessBool => bool_simplification::needless_bool::LINT_NAME,
+            CairoLintKind::NonminimalBool => bool_simplification::nonminimal_bool::LINT_NAME,
+            CairoLintKind::StorageWriteAfterExternalCall => {
+                storage_write_after_external_call::LINT_NAME
+            }
+            CairoLintKind::UnprotectedPrivilegedOperation => {
+                unprotected_privileged_operation::LINT_NAME
+            }
+            CairoLintKind::UncheckedL1HandlerFromAddress => {
+                unchecked_l1_handler_from_address::LINT_NAME
+            }
+            CairoLintKind::BlockInfoDependence => block_info_dependence::LINT_NAME,
+            CairoLintKind::UnemittedEvent => unemitted_event::LINT_NAME,
+            CairoLintKind::UnusedStorageField => unused_storage_field::LINT_NAME,
+            CairoLintKind::MissingZeroAddressCheck => missing_zero_address_check::LINT_NAME,
+            CairoLintKind::ArrayParamOnlyRead => array_param_only_read::LINT_NAME,
+            CairoLintKind::RedundantClone => redundant_clone::LINT_NAME,
+            CairoLintKind::Unknown
+            | CairoLintKind::UnusedAllow
+            | CairoLintKind::UnfulfilledLintExpectation => return None,
+        };
+        Some(lint_name)
+    }
 }
 
 pub fn diagnostic_kind_from_message(message: &str) -> CairoLintKind {
@@ -66,9 +345,10 @@ pub fn diagnostic_kind_from_message(message: &str) -> CairoLintKind {
         single_match::DESTRUCT_MATCH => CairoLintKind::DestructMatch,
         single_match::MATCH_FOR_EQUALITY => CairoLintKind::MatchForEquality,
         double_parens::DOUBLE_PARENS => CairoLintKind::DoubleParens,
-        double_comparison::SIMPLIFIABLE_COMPARISON => CairoLintKind::DoubleComparison,
-        double_comparison::REDUNDANT_COMPARISON => CairoLintKind::DoubleComparison,
-        double_comparison::CONTRADICTORY_COMPARISON => CairoLintKind::DoubleComparison,
+        double_comparison::SIMPLIFIABLE_COMPARISON => CairoLintKind::SimplifiableComparison,
+        double_comparison::REDUNDANT_COMPARISON => CairoLintKind::RedundantComparison,
+        double_comparison::CONTRADICTORY_COMPARISON => CairoLintKind::ContradictoryComparison,
+        double_comparison::IMPOSSIBLE_COMPARISON => CairoLintKind::ImposibleComparison,
         breaks::BREAK_UNIT => CairoLintKind::BreakUnit,
         equatable_if_let::EQUATABLE_IF_LET => CairoLintKind::EquatableIfLet,
         bool_comparison::BOOL_COMPARISON => CairoLintKind::BoolComparison,
@@ -79,6 +359,13 @@ pub fn diagnostic_kind_from_message(message: &str) -> CairoLintKind {
         collapsible_if::COLLAPSIBLE_IF => CairoLintKind::CollapsibleIf,
         loop_match_pop_front::LOOP_MATCH_POP_FRONT => CairoLintKind::LoopMatchPopFront,
         manual_unwrap_or_default::MANUAL_UNWRAP_OR_DEFAULT => CairoLintKind::ManualUnwrapOrDefault,
+        manual_unwrap_or::MANUAL_UNWRAP_OR => CairoLintKind::ManualUnwrapOr,
+        manual_unwrap_or::MANUAL_UNWRAP_OR_ELSE => CairoLintKind::ManualUnwrapOrElse,
+        manual_map::MANUAL_MAP => CairoLintKind::ManualMap,
+        manual_map_err::MANUAL_MAP_ERR => CairoLintKind::ManualMapErr,
+        manual_and_then::MANUAL_AND_THEN => CairoLintKind::ManualAndThen,
+        manual_filter::MANUAL_FILTER => CairoLintKind::ManualFilter,
+        manual_flatten::MANUAL_FLATTEN => CairoLintKind::ManualFlatten,
         panic::PANIC_IN_CODE => CairoLintKind::Panic,
         loop_for_while::LOOP_FOR_WHILE => CairoLintKind::LoopForWhile,
         erasing_op::ERASING_OPERATION => CairoLintKind::ErasingOperation,
@@ -97,10 +384,62 @@ pub fn diagnostic_kind_from_message(message: &str) -> CairoLintKind {
         int_op_one::INT_GE_MIN_ONE => CairoLintKind::IntGeMinOne,
         int_op_one::INT_LE_PLUS_ONE => CairoLintKind::IntLePlusOne,
         int_op_one::INT_LE_MIN_ONE => CairoLintKind::IntLeMinOne,
+        eq_op::DIV_EQ_OP
+        | eq_op::EQ_COMP_OP
+        | eq_op::NEQ_COMP_OP
+        | eq_op::EQ_DIFF_OP
+        | eq_op::EQ_BITWISE_OP
+        | eq_op::EQ_LOGICAL_OP => CairoLintKind::EqOp,
+        array_init_then_append::ARRAY_INIT_THEN_APPEND => CairoLintKind::ArrayInitThenAppend,
+        felt252_arithmetic::FELT252_COMPARISON => CairoLintKind::Felt252Comparison,
+        felt252_arithmetic::FELT252_DIVISION => CairoLintKind::Felt252Division,
+        felt252_arithmetic::FELT252_SUB_UNDERFLOW => CairoLintKind::Felt252SubUnderflow,
+        div_before_mul::DIV_BEFORE_MUL => CairoLintKind::DivBeforeMul,
+        absurd_extreme_comparisons::ABSURD_COMPARISON_ALWAYS_TRUE
+        | absurd_extreme_comparisons::ABSURD_COMPARISON_ALWAYS_FALSE => {
+            CairoLintKind::AbsurdExtremeComparison
+        }
+        try_into_unwrap::TRY_INTO_UNWRAP => CairoLintKind::TryIntoUnwrap,
+        try_into_unwrap::LOSSLESS_TRY_INTO_UNWRAP => CairoLintKind::LosslessTryIntoUnwrap,
+        identity_op::IDENTITY_OP => CairoLintKind::IdentityOp,
+        returns::NEEDLESS_RETURN => CairoLintKind::NeedlessReturn,
+        returns::LET_AND_RETURN => CairoLintKind::LetAndReturn,
+        bool_simplification::NEEDLESS_BOOL => CairoLintKind::NeedlessBool,
+        bool_simplification::NONMINIMAL_BOOL => CairoLintKind::NonminimalBool,
+        storage_write_after_external_call::STORAGE_WRITE_AFTER_EXTERNAL_CALL => {
+            CairoLintKind::StorageWriteAfterExternalCall
+        }
+        unprotected_privileged_operation::UNPROTECTED_PRIVILEGED_OPERATION => {
+            CairoLintKind::UnprotectedPrivilegedOperation
+        }
+        unchecked_l1_handler_from_address::UNUSED_L1_HANDLER_FROM_ADDRESS
+        | unchecked_l1_handler_from_address::UNCHECKED_L1_HANDLER_FROM_ADDRESS => {
+            CairoLintKind::UncheckedL1HandlerFromAddress
+        }
+        performance::INEFFICIENT_WHILE_COMP_MESSAGE => CairoLintKind::InefficientWhileComp,
+        performance::STORAGE_READ_IN_LOOP => CairoLintKind::StorageReadInLoop,
+        block_info_dependence::BLOCK_INFO_RANDOMNESS
+        | block_info_dependence::BLOCK_INFO_STRICT_EQUALITY => CairoLintKind::BlockInfoDependence,
+        unemitted_event::UNEMITTED_EVENT => CairoLintKind::UnemittedEvent,
+        unused_storage_field::WRITE_ONLY_STORAGE_FIELD
+        | unused_storage_field::UNUSED_STORAGE_FIELD => CairoLintKind::UnusedStorageField,
+        missing_zero_address_check::MISSING_ZERO_ADDRESS_CHECK => {
+            CairoLintKind::MissingZeroAddressCheck
+        }
+        array_param_only_read::ARRAY_PARAM_ONLY_READ => CairoLintKind::ArrayParamOnlyRead,
+        redundant_clone::REDUNDANT_CLONE => CairoLintKind::RedundantClone,
+        suppression::UNUSED_ALLOW => CairoLintKind::UnusedAllow,
+        suppression::UNFULFILLED_LINT_EXPECTATION => CairoLintKind::UnfulfilledLintExpectation,
         _ => CairoLintKind::Unknown,
     }
 }
 
+/// Gets the name of the lint, as used in `#[allow(...)]`, that emitted a diagnostic with this
+/// message.
+pub fn lint_name_from_message(message: &str) -> Option<&'static str> {
+    diagnostic_kind_from_message(message).lint_name()
+}
+
 impl AnalyzerPlugin for CairoLint {
     fn declared_allows(&self) -> Vec<String> {
         vec![
@@ -120,6 +459,17 @@ impl AnalyzerPlugin for CairoLint {
             manual::ALLOWED.as_slice(),
             performance::ALLOWED.as_slice(),
             int_op_one::ALLOWED.as_slice(),
+            array_init_then_append::ALLOWED.as_slice(),
+            felt252_arithmetic::ALLOWED.as_slice(),
+            div_before_mul::ALLOWED.as_slice(),
+            absurd_extreme_comparisons::ALLOWED.as_slice(),
+            try_into_unwrap::ALLOWED.as_slice(),
+            identity_op::ALLOWED.as_slice(),
+            returns::ALLOWED.as_slice(),
+            bool_simplification::ALLOWED.as_slice(),
+            starknet_security::ALLOWED.as_slice(),
+            array_param_only_read::ALLOWED.as_slice(),
+            redundant_clone::ALLOWED.as_slice(),
         ]
         .into_iter()
         .flatten()
@@ -128,73 +478,149 @@ impl AnalyzerPlugin for CairoLint {
     }
 
     fn diagnostics(&self, db: &dyn SemanticGroup, module_id: ModuleId) -> Vec<PluginDiagnostic> {
-        let mut diags = Vec::new();
-        let syntax_db = db.upcast();
-        let Ok(items) = db.module_items(module_id) else {
-            return diags;
-        };
-        for item in &*items {
-            let function_nodes = match item {
-                ModuleItemId::Constant(constant_id) => constant_id
-                    .stable_ptr(db.upcast())
-                    .lookup(syntax_db)
-                    .as_syntax_node(),
-                ModuleItemId::FreeFunction(free_function_id) => {
-                    let func_id = FunctionWithBodyId::Free(*free_function_id);
-                    check_function(db, func_id, &mut diags);
-                    free_function_id
-                        .stable_ptr(db.upcast())
-                        .lookup(syntax_db)
-                        .as_syntax_node()
-                }
-                ModuleItemId::Impl(impl_id) => {
-                    let impl_functions = db.impl_functions(*impl_id);
-                    let Ok(functions) = impl_functions else {
-                        continue;
-                    };
-                    for (_fn_name, fn_id) in functions.iter() {
-                        let func_id = FunctionWithBodyId::Impl(*fn_id);
-                        check_function(db, func_id, &mut diags);
-                    }
-                    impl_id
-                        .stable_ptr(db.upcast())
-                        .lookup(syntax_db)
-                        .as_syntax_node()
-                }
-                _ => continue,
-            }
-            .descendants(syntax_db);
+        let mut details = Vec::new();
+        let diagnostics = self.lint_module(db, module_id, &mut details);
+        self.details.set_module_details(module_id, details);
+        diagnostics
+    }
+}
+/// Checks if the item was generated by a plugin, e.g. the wrappers and dispatchers of
+/// `#[starknet::contract]` modules, as its diagnostics can't be acted upon by the user.
+fn is_generated_item(db: &dyn SemanticGroup, item: &ModuleItemId) -> bool {
+    let stable_ptr = match item {
+        ModuleItemId::FreeFunction(free_function_id) => {
+            free_function_id.stable_ptr(db.upcast()).untyped()
+        }
+        ModuleItemId::Impl(impl_id) => impl_id.stable_ptr(db.upcast()).untyped(),
+        _ => return false,
+    };
+    is_macro_generated(db, stable_ptr)
+}
 
-            for node in function_nodes {
-                match node.kind(syntax_db) {
-                    SyntaxKind::ExprParenthesized => double_parens::check_double_parens(
-                        db.upcast(),
-                        &AstExpr::from_syntax_node(db.upcast(), node.clone()),
-                        &mut diags,
-                    ),
-                    SyntaxKind::ExprIf => {}
-                    SyntaxKind::ExprMatch => {}
-                    _ => continue,
+/// The struct generated by `#[starknet::contract]` to give access to the storage of the contract.
+const CONTRACT_STATE: &str = "ContractState";
+
+/// Checks the `#[starknet::contract]` module as a whole, given the functions written by the user in
+/// it. The functions of the crate accessing its storage from outside of it, i.e. the ones of its
+/// submodules and the ones taking its `ContractState`, are checked along with them.
+fn check_contract(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    contract: &ast::ItemModule,
+    functions: &[FunctionWithBodyId],
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) {
+    let mut functions = functions.to_vec();
+    functions.extend(outer_contract_functions(db, module_id));
+    unemitted_event::check_unemitted_event(db, module_id, contract, &functions, diagnostics);
+    unused_storage_field::check_unused_storage_field(db, contract, &functions, diagnostics);
+}
+
+/// Gets the functions of the crate outside of the contract module that can access its storage:
+/// the functions of its submodules and the ones taking its `ContractState`.
+fn outer_contract_functions(
+    db: &dyn SemanticGroup,
+    contract_module_id: ModuleId,
+) -> Vec<FunctionWithBodyId> {
+    let contract_state = match db.module_item_by_name(contract_module_id, CONTRACT_STATE.into()) {
+        Ok(Some(ModuleItemId::Struct(struct_id))) => Some(struct_id),
+        _ => None,
+    };
+    let crate_modules = db.crate_modules(contract_module_id.owning_crate(db.upcast()));
+    crate_modules
+        .iter()
+        .filter(|module_id| **module_id != contract_module_id)
+        .flat_map(|module_id| {
+            let is_submodule = is_submodule_of(db, *module_id, contract_module_id);
+            module_functions(db, *module_id)
+                .into_iter()
+                .filter(move |func_id| {
+                    is_submodule
+                        || contract_state
+                            .is_some_and(|struct_id| takes_struct(db, *func_id, struct_id))
+                })
+        })
+        .collect()
+}
+
+/// Gets the functions written by the user in the module.
+pub(crate) fn module_functions(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+) -> Vec<FunctionWithBodyId> {
+    let Ok(items) = db.module_items(module_id) else {
+        return Vec::new();
+    };
+    let mut functions = Vec::new();
+    for item in items.iter().filter(|item| !is_generated_item(db, item)) {
+        match item {
+            ModuleItemId::FreeFunction(free_function_id) => {
+                functions.push(FunctionWithBodyId::Free(*free_function_id))
+            }
+            ModuleItemId::Impl(impl_id) => {
+                if let Ok(impl_functions) = db.impl_functions(*impl_id) {
+                    functions.extend(
+                        impl_functions
+                            .iter()
+                            .map(|(_fn_name, fn_id)| FunctionWithBodyId::Impl(*fn_id)),
+                    );
                 }
             }
+            _ => {}
+        }
+    }
+    functions
+}
+
+fn is_submodule_of(db: &dyn SemanticGroup, module_id: ModuleId, ancestor_id: ModuleId) -> bool {
+    let mut current_module_id = module_id;
+    while let ModuleId::Submodule(submodule_id) = current_module_id {
+        current_module_id = submodule_id.parent_module(db.upcast());
+        if current_module_id == ancestor_id {
+            return true;
         }
-        diags
     }
+    false
+}
+
+/// Checks if the function takes the struct, or a snapshot of it, as a parameter.
+fn takes_struct(db: &dyn SemanticGroup, func_id: FunctionWithBodyId, struct_id: StructId) -> bool {
+    let is_struct = |mut ty: TypeId| loop {
+        match db.lookup_intern_type(ty) {
+            TypeLongId::Snapshot(inner_ty) => ty = inner_ty,
+            TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+                return concrete_struct_id.struct_id(db) == struct_id
+            }
+            _ => return false,
+        }
+    };
+    db.function_with_body_signature(func_id)
+        .is_ok_and(|signature| signature.params.iter().any(|param| is_struct(param.ty)))
 }
+
 fn check_function(
     db: &dyn SemanticGroup,
     func_id: FunctionWithBodyId,
+    config: &LintConfig,
     diagnostics: &mut Vec<PluginDiagnostic>,
+    details: &mut Vec<AttachedDetails>,
 ) {
-    if let Ok(false) = func_id.has_attr_with_arg(db, "allow", "duplicate_underscore_args") {
-        duplicate_underscore_args::check_duplicate_underscore_args(
-            db.function_with_body_signature(func_id).unwrap().params,
-            diagnostics,
-        );
-    }
+    duplicate_underscore_args::check_duplicate_underscore_args(
+        db,
+        db.function_with_body_signature(func_id).unwrap().params,
+        diagnostics,
+        details,
+    );
     let Ok(function_body) = db.function_body(func_id) else {
         return;
     };
+    array_param_only_read::check_array_param_only_read(
+        db,
+        func_id,
+        &function_body.arenas,
+        diagnostics,
+    );
+    redundant_clone::check_redundant_clone(db, func_id, &function_body.arenas, diagnostics);
     for (_expression_id, expression) in &function_body.arenas.exprs {
         match &expression {
             Expr::Match(expr_match) => {
@@ -231,6 +657,37 @@ fn check_function(
                     expr_match,
                     diagnostics,
                 );
+                manual_unwrap_or::check_manual_unwrap_or(
+                    db,
+                    &function_body.arenas,
+                    expr_match,
+                    diagnostics,
+                );
+                manual_map::check_manual_map(db, &function_body.arenas, expr_match, diagnostics);
+                manual_map_err::check_manual_map_err(
+                    db,
+                    &function_body.arenas,
+                    expr_match,
+                    diagnostics,
+                );
+                manual_and_then::check_manual_and_then(
+                    db,
+                    &function_body.arenas,
+                    expr_match,
+                    diagnostics,
+                );
+                manual_filter::check_manual_filter(
+                    db,
+                    &function_body.arenas,
+                    expr_match,
+                    diagnostics,
+                );
+                manual_flatten::check_manual_flatten(
+                    db,
+                    &function_body.arenas,
+                    expr_match,
+                    diagnostics,
+                );
             }
             Expr::Loop(expr_loop) => {
                 loop_match_pop_front::check_loop_match_pop_front(
@@ -245,6 +702,12 @@ fn check_function(
                     &function_body.arenas,
                     diagnostics,
                 );
+                performance::check_storage_read_in_loop(
+                    db,
+                    expression,
+                    &function_body.arenas,
+                    diagnostics,
+                );
             }
             Expr::FunctionCall(expr_func) => {
                 panic::check_panic_usage(db, expr_func, diagnostics);
@@ -261,13 +724,44 @@ fn check_function(
                     &function_body.arenas,
                     diagnostics,
                 );
-                eq_op::check_eq_op(db, expr_func, &function_body.arenas, diagnostics);
+                eq_op::check_eq_op(db, expr_func, &function_body.arenas, diagnostics, details);
                 erasing_op::check_erasing_operation(
                     db,
                     expr_func,
                     &function_body.arenas,
                     diagnostics,
                 );
+                identity_op::check_identity_op(db, expr_func, &function_body.arenas, diagnostics);
+                felt252_arithmetic::check_felt252_arithmetic(
+                    db,
+                    expr_func,
+                    &function_body.arenas,
+                    diagnostics,
+                );
+                div_before_mul::check_div_before_mul(
+                    db,
+                    expr_func,
+                    &function_body.arenas,
+                    diagnostics,
+                );
+                absurd_extreme_comparisons::check_absurd_extreme_comparisons(
+                    db,
+                    expr_func,
+                    &function_body.arenas,
+                    diagnostics,
+                );
+                try_into_unwrap::check_try_into_unwrap(
+                    db,
+                    expr_func,
+                    &function_body.arenas,
+                    diagnostics,
+                );
+                bool_simplification::check_nonminimal_bool(
+                    db,
+                    expr_func,
+                    &function_body.arenas,
+                    diagnostics,
+                );
             }
 
             Expr::LogicalOperator(expr_logical) => {
@@ -302,6 +796,7 @@ fn check_function(
                     expr_if,
                     &function_body.arenas,
                     diagnostics,
+                    details,
                 );
                 manual_is::check_manual_if_is(db, &function_body.arenas, expr_if, diagnostics);
                 manual_expect::check_manual_if_expect(
@@ -330,16 +825,106 @@ fn check_function(
                     expr_if,
                     diagnostics,
                 );
+                manual_unwrap_or::check_manual_if_unwrap_or(
+                    db,
+                    &function_body.arenas,
+                    expr_if,
+                    diagnostics,
+                );
+                manual_map::check_manual_if_map(db, &function_body.arenas, expr_if, diagnostics);
+                manual_and_then::check_manual_if_and_then(
+                    db,
+                    &function_body.arenas,
+                    expr_if,
+                    diagnostics,
+                );
+                manual_filter::check_manual_if_filter(
+                    db,
+                    &function_body.arenas,
+                    expr_if,
+                    diagnostics,
+                );
+                manual_flatten::check_manual_if_flatten(
+                    db,
+                    &function_body.arenas,
+                    expr_if,
+                    diagnostics,
+                );
+                bool_simplification::check_needless_bool(
+                    db,
+                    expr_if,
+                    &function_body.arenas,
+                    diagnostics,
+                );
             }
-            Expr::While(expr_while) => performance::check_inefficient_while_comp(
+            Expr::While(expr_while) => {
+                performance::check_inefficient_while_comp(
+                    db,
+                    expr_while,
+                    diagnostics,
+                    &function_body.arenas,
+                );
+                performance::check_storage_read_in_loop(
+                    db,
+                    expression,
+                    &function_body.arenas,
+                    diagnostics,
+                );
+            }
+            Expr::For(_) => performance::check_storage_read_in_loop(
                 db,
-                expr_while,
-                diagnostics,
+                expression,
                 &function_body.arenas,
+                diagnostics,
             ),
+            Expr::Block(expr_block) => {
+                array_init_then_append::check_array_init_then_append(
+                    db,
+                    expr_block,
+                    &function_body.arenas,
+                    diagnostics,
+                );
+                returns::check_let_and_return(db, expr_block, &function_body.arenas, diagnostics);
+            }
             _ => (),
         };
     }
+    returns::check_needless_return(
+        db,
+        function_body.body_expr,
+        &function_body.arenas,
+        diagnostics,
+    );
+    storage_write_after_external_call::check_storage_write_after_external_call(
+        db,
+        &function_body.arenas,
+        diagnostics,
+    );
+    block_info_dependence::check_block_info_dependence(
+        db,
+        func_id,
+        &function_body.arenas,
+        diagnostics,
+    );
+    unprotected_privileged_operation::check_unprotected_privileged_operation(
+        db,
+        func_id,
+        &config.guard_functions,
+        diagnostics,
+    );
+    unchecked_l1_handler_from_address::check_unchecked_l1_handler_from_address(
+        db,
+        func_id,
+        &function_body.arenas,
+        diagnostics,
+    );
+    missing_zero_address_check::check_missing_zero_address_check(
+        db,
+        func_id,
+        &function_body.arenas,
+        &config.zero_address_guard_functions,
+        diagnostics,
+    );
     for (_stmt_id, stmt) in &function_body.arenas.statements {
         if let Statement::Break(stmt_break) = &stmt {
             breaks::check_break(db, stmt_break, &function_body.arenas, diagnostics)
@@ -0,0 +1,247 @@
+use std::collections::{HashMap, HashSet};
+
+use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextSpan, TextWidth};
+use cairo_lang_syntax::node::ast::{self, OptionArgListParenthesized};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+
+use crate::plugin::lint_name_from_message;
+
+pub const UNUSED_ALLOW: &str =
+    "This lint is never emitted in the scope of the `allow` attribute. Consider removing it.";
+pub const UNFULFILLED_LINT_EXPECTATION: &str =
+    "Unfulfilled lint expectation: this lint is never emitted in the scope of the `expect` \
+     attribute.";
+
+pub const ALLOW_ATTR: &str = "allow";
+pub const EXPECT_ATTR: &str = "expect";
+
+/// Prefix of the comments controlling the lints, e.g. `// cairo-lint: allow(double_parens)`.
+const DIRECTIVE_PREFIX: &str = "cairo-lint:";
+/// Allows the lints for the next statement or item.
+const ALLOW_DIRECTIVE: &str = "allow";
+/// Allows the lints for the whole file, must be at the top of the file.
+const ALLOW_FILE_DIRECTIVE: &str = "allow-file";
+/// Allows the lints until the next `enable` directive or the end of the file.
+const DISABLE_DIRECTIVE: &str = "disable";
+const ENABLE_DIRECTIVE: &str = "enable";
+
+/// Removes the diagnostics of the lints allowed or expected in an upper scope (the node of the
+/// diagnostic included), either with an attribute or with a `// cairo-lint: ...` comment.
+///
+/// Then reports the arguments of the `#[expect]` attributes found in `items`, and of the `#[allow]`
+/// ones if `report_unused_allows` is set, that didn't suppress any diagnostic. Only the lints from
+/// `lint_names` are reported as we can't know if the lints of the compiler were emitted or not.
+pub fn suppress_diagnostics(
+    db: &dyn SyntaxGroup,
+    items: &[SyntaxNode],
+    lint_names: &[String],
+    report_unused_allows: bool,
+    diagnostics: Vec<PluginDiagnostic>,
+) -> Vec<PluginDiagnostic> {
+    let mut used_args = HashSet::new();
+    let mut disabled_ranges = HashMap::new();
+    let mut diagnostics = diagnostics
+        .into_iter()
+        .filter(|diag| {
+            let Some(lint_name) = lint_name_from_message(&diag.message) else {
+                return true;
+            };
+            let node = diag.stable_ptr.lookup(db);
+            if let Some(arg) = suppressing_arg(db, node.clone(), lint_name) {
+                used_args.insert(arg);
+                return false;
+            }
+            !is_allowed_by_comment(db, node.clone(), lint_name)
+                && !is_in_disabled_range(db, node, lint_name, &mut disabled_ranges)
+        })
+        .collect::<Vec<_>>();
+
+    for node in items.iter().flat_map(|item| item.descendants(db)) {
+        if node.kind(db) != SyntaxKind::Attribute {
+            continue;
+        }
+        let attr = ast::Attribute::from_syntax_node(db, node);
+        let message = match attr
+            .attr(db)
+            .as_syntax_node()
+            .get_text_without_trivia(db)
+            .as_str()
+        {
+            ALLOW_ATTR if report_unused_allows => UNUSED_ALLOW,
+            EXPECT_ATTR => UNFULFILLED_LINT_EXPECTATION,
+            _ => continue,
+        };
+        for arg in attribute_args(db, &attr) {
+            let arg_ptr = arg.stable_ptr().untyped();
+            if lint_names.contains(&arg.as_syntax_node().get_text_without_trivia(db))
+                && !used_args.contains(&arg_ptr)
+            {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: arg_ptr,
+                    message: message.to_string(),
+                    severity: Severity::Warning,
+                });
+            }
+        }
+    }
+    diagnostics
+}
+
+/// Finds the innermost `#[allow(lint_name)]` or `#[expect(lint_name)]` in the scope of `node` and
+/// returns the stable pointer of its argument.
+fn suppressing_arg(
+    db: &dyn SyntaxGroup,
+    node: SyntaxNode,
+    lint_name: &str,
+) -> Option<SyntaxStablePtrId> {
+    let mut current_node = Some(node);
+    while let Some(node) = current_node {
+        let attrs = [ALLOW_ATTR, EXPECT_ATTR]
+            .into_iter()
+            .flat_map(|attr_name| node.query_attr(db, attr_name));
+        for attr in attrs {
+            if let Some(arg) = attribute_args(db, &attr)
+                .into_iter()
+                .find(|arg| arg.as_syntax_node().get_text_without_trivia(db) == lint_name)
+            {
+                return Some(arg.stable_ptr().untyped());
+            }
+        }
+        current_node = node.parent();
+    }
+    None
+}
+
+fn attribute_args(db: &dyn SyntaxGroup, attr: &ast::Attribute) -> Vec<ast::Arg> {
+    match attr.arguments(db) {
+        OptionArgListParenthesized::ArgListParenthesized(args) => args.arguments(db).elements(db),
+        OptionArgListParenthesized::Empty(_) => vec![],
+    }
+}
+
+/// Checks if the lint is allowed by a `// cairo-lint: allow(...)` comment right above one of the
+/// statements or items containing `node`, or by a `// cairo-lint: allow-file(...)` comment at the
+/// top of the file.
+fn is_allowed_by_comment(db: &dyn SyntaxGroup, node: SyntaxNode, lint_name: &str) -> bool {
+    let mut current_node = Some(node);
+    while let Some(node) = current_node {
+        let directive_name = match node.kind(db) {
+            SyntaxKind::SyntaxFile => Some(ALLOW_FILE_DIRECTIVE),
+            SyntaxKind::StatementLet
+            | SyntaxKind::StatementExpr
+            | SyntaxKind::StatementReturn
+            | SyntaxKind::StatementBreak
+            | SyntaxKind::StatementContinue
+            | SyntaxKind::FunctionWithBody
+            | SyntaxKind::TraitItemFunction
+            | SyntaxKind::ItemConstant
+            | SyntaxKind::ItemImpl
+            | SyntaxKind::ItemModule
+            | SyntaxKind::ItemTrait => Some(ALLOW_DIRECTIVE),
+            _ => None,
+        };
+        if let Some(directive_name) = directive_name {
+            let leading_trivia = TextSpan {
+                start: node.span(db).start,
+                end: node.span_start_without_trivia(db),
+            };
+            let leading_trivia = node.clone().get_text_of_span(db, leading_trivia);
+            if comment_directives(&leading_trivia)
+                .any(|(_, name, lints)| name == directive_name && lints.contains(&lint_name))
+            {
+                return true;
+            }
+        }
+        current_node = node.parent();
+    }
+    false
+}
+
+/// Checks if `node` is between a `// cairo-lint: disable(...)` comment disabling the lint and the
+/// next `// cairo-lint: enable(...)` comment enabling it back.
+fn is_in_disabled_range(
+    db: &dyn SyntaxGroup,
+    node: SyntaxNode,
+    lint_name: &str,
+    disabled_ranges: &mut HashMap<FileId, Vec<(String, TextSpan)>>,
+) -> bool {
+    let offset = node.span_start_without_trivia(db);
+    let mut root = node;
+    while let Some(parent) = root.parent() {
+        root = parent;
+    }
+    disabled_ranges
+        .entry(root.stable_ptr().file_id(db))
+        .or_insert_with(|| file_disabled_ranges(db, &root))
+        .iter()
+        .any(|(lint, span)| lint == lint_name && span.start <= offset && offset < span.end)
+}
+
+/// Collects the ranges disabled by the `disable`/`enable` directives in the comments of a file.
+fn file_disabled_ranges(db: &dyn SyntaxGroup, root: &SyntaxNode) -> Vec<(String, TextSpan)> {
+    let mut ranges = Vec::new();
+    let mut disabled_since = HashMap::new();
+    for trivia in root
+        .descendants(db)
+        .filter(|node| node.kind(db) == SyntaxKind::Trivia)
+    {
+        let text = trivia.get_text(db);
+        for (line_offset, name, lints) in comment_directives(&text) {
+            let offset = trivia
+                .offset()
+                .add_width(TextWidth::from_str(&text[..line_offset]));
+            for lint in lints {
+                match name {
+                    DISABLE_DIRECTIVE => {
+                        disabled_since.entry(lint.to_string()).or_insert(offset);
+                    }
+                    ENABLE_DIRECTIVE => {
+                        if let Some(start) = disabled_since.remove(lint) {
+                            ranges.push((lint.to_string(), TextSpan { start, end: offset }));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    let end = root.span(db).end;
+    ranges.extend(
+        disabled_since
+            .into_iter()
+            .map(|(lint, start)| (lint, TextSpan { start, end })),
+    );
+    ranges
+}
+
+/// Parses the `// cairo-lint: <directive>(<lint>, ...)` comments in a trivia text and returns
+/// their offset in the text, the directive and the lints.
+fn comment_directives(text: &str) -> impl Iterator<Item = (usize, &str, Vec<&str>)> {
+    text.split_inclusive('\n')
+        .scan(0, |line_offset, line| {
+            let offset = *line_offset;
+            *line_offset += line.len();
+            Some((offset, line))
+        })
+        .filter_map(|(offset, line)| {
+            let directive = line
+                .trim()
+                .strip_prefix("//")?
+                .trim_start()
+                .strip_prefix(DIRECTIVE_PREFIX)?;
+            let (name, lints) = directive.trim().strip_suffix(')')?.split_once('(')?;
+            let lints = lints
+                .split(',')
+                .map(str::trim)
+                .filter(|lint| !lint.is_empty())
+                .collect();
+            Some((offset, name.trim(), lints))
+        })
+}
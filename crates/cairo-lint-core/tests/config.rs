@@ -0,0 +1,236 @@
+use std::path::{Path, PathBuf};
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
+use cairo_lang_semantic::test_utils::setup_test_crate_ex;
+use cairo_lang_starknet::starknet_plugin_suite;
+use cairo_lang_utils::Upcast;
+use cairo_lint_core::config::{LintConfig, LintLevel, LintOverride};
+use cairo_lint_core::lints::starknet_security::{
+    missing_zero_address_check, unprotected_privileged_operation,
+};
+use cairo_lint_core::plugin::cairo_lint_plugin_suite_with_config;
+use cairo_lint_core::suppression;
+use cairo_lint_test_utils::get_diags;
+
+const CRATE_CONFIG: &str = r#"
+edition = "2024_07"
+"#;
+
+/// Gets the messages of the lint diagnostics of the code, linted with the configuration.
+fn lint_messages(config: LintConfig, code: &str) -> Vec<String> {
+    let mut db = RootDatabase::builder()
+        .with_plugin_suite(get_default_plugin_suite())
+        .with_plugin_suite(starknet_plugin_suite())
+        .with_plugin_suite(cairo_lint_plugin_suite_with_config(config))
+        .build()
+        .unwrap();
+    let crate_id = setup_test_crate_ex(db.upcast(), code, Some(CRATE_CONFIG));
+    get_diags(crate_id, &mut db)
+        .into_iter()
+        .flat_map(|diags| diags.get_all())
+        .filter_map(|diag| match diag.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(plugin_diag) => Some(plugin_diag.message),
+            _ => None,
+        })
+        .collect()
+}
+
+const GOVERNED_CONTRACT: &str = r#"
+#[starknet::contract]
+mod Governed {
+    use core::num::traits::Zero;
+    use starknet::ContractAddress;
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+
+    #[storage]
+    struct Storage {
+        owner: ContractAddress,
+        governance_enabled: bool,
+    }
+
+    #[external(v0)]
+    fn set_owner(ref self: ContractState, new_owner: ContractAddress) {
+        self.only_governance();
+        assert(new_owner.is_non_zero(), 'Zero address');
+        self.owner.write(new_owner);
+    }
+
+    #[external(v0)]
+    fn get_owner(self: @ContractState) -> ContractAddress {
+        self.owner.read()
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        fn only_governance(self: @ContractState) {
+            assert(self.governance_enabled.read(), 'Governance disabled');
+        }
+    }
+}
+"#;
+
+#[test]
+fn guard_functions() {
+    let unprotected =
+        unprotected_privileged_operation::UNPROTECTED_PRIVILEGED_OPERATION.to_string();
+    assert!(lint_messages(LintConfig::default(), GOVERNED_CONTRACT).contains(&unprotected));
+    for guard in [
+        "only_governance",
+        "test::Governed::InternalImpl::only_governance",
+    ] {
+        let config = LintConfig {
+            guard_functions: vec![guard.to_string()],
+            ..Default::default()
+        };
+        assert!(!lint_messages(config, GOVERNED_CONTRACT).contains(&unprotected));
+    }
+}
+
+const ADDRESS_GUARDED_CONTRACT: &str = r#"
+#[starknet::contract]
+mod Treasury {
+    use starknet::ContractAddress;
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+
+    #[storage]
+    struct Storage {
+        treasury: ContractAddress,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, treasury: ContractAddress) {
+        assert_valid_address(treasury);
+        self.treasury.write(treasury);
+    }
+
+    #[external(v0)]
+    fn get_treasury(self: @ContractState) -> ContractAddress {
+        self.treasury.read()
+    }
+
+    fn assert_valid_address(address: ContractAddress) {
+        let raw_address: felt252 = address.into();
+        assert(raw_address != 0, 'Invalid address');
+    }
+}
+"#;
+
+#[test]
+fn zero_address_guard_functions() {
+    let missing_check = missing_zero_address_check::MISSING_ZERO_ADDRESS_CHECK.to_string();
+    assert!(
+        lint_messages(LintConfig::default(), ADDRESS_GUARDED_CONTRACT).contains(&missing_check)
+    );
+    for guard in [
+        "assert_valid_address",
+        "test::Treasury::assert_valid_address",
+    ] {
+        let config = LintConfig {
+            zero_address_guard_functions: vec![guard.to_string()],
+            ..Default::default()
+        };
+        assert!(!lint_messages(config, ADDRESS_GUARDED_CONTRACT).contains(&missing_check));
+    }
+}
+
+const UNUSED_ALLOW_CODE: &str = r#"
+#[allow(double_parens)]
+fn main() -> u32 {
+    1
+}
+"#;
+
+#[test]
+fn unused_allow() {
+    let unused_allow = suppression::UNUSED_ALLOW.to_string();
+    assert!(!lint_messages(LintConfig::default(), UNUSED_ALLOW_CODE).contains(&unused_allow));
+    let config = LintConfig {
+        unused_allow: true,
+        ..Default::default()
+    };
+    assert!(lint_messages(config, UNUSED_ALLOW_CODE).contains(&unused_allow));
+}
+
+/// Gets an override of the level of `double_parens` for the paths and the modules.
+fn double_parens_override(paths: &[&str], modules: &[&str], level: LintLevel) -> LintOverride {
+    LintOverride::new(
+        &paths
+            .iter()
+            .map(|path| path.to_string())
+            .collect::<Vec<_>>(),
+        modules.iter().map(|module| module.to_string()).collect(),
+        vec![("double_parens".to_string(), level)],
+    )
+    .unwrap()
+}
+
+/// Gets the configuration of a package at `/package` with the overrides.
+fn config_with_overrides(overrides: Vec<LintOverride>) -> LintConfig {
+    LintConfig {
+        root: PathBuf::from("/package"),
+        overrides,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn override_paths_relative_to_root() {
+    let config = config_with_overrides(vec![double_parens_override(
+        &["src/tests/**"],
+        &[],
+        LintLevel::Allow,
+    )]);
+    let level = |file_path: &str| config.lint_level("double_parens", Path::new(file_path), "pkg");
+    assert_eq!(
+        level("/package/src/tests/utils.cairo"),
+        Some(LintLevel::Allow)
+    );
+    assert_eq!(level("/package/src/lib.cairo"), None);
+    assert_eq!(level("/other/src/tests/utils.cairo"), None);
+    assert_eq!(level("src/tests/utils.cairo"), None);
+}
+
+#[test]
+fn override_modules_with_submodules() {
+    let config = config_with_overrides(vec![double_parens_override(
+        &[],
+        &["pkg::a::b"],
+        LintLevel::Deny,
+    )]);
+    let file_path = Path::new("/package/src/lib.cairo");
+    let level = |module_path: &str| config.lint_level("double_parens", file_path, module_path);
+    assert_eq!(level("pkg::a::b"), Some(LintLevel::Deny));
+    assert_eq!(level("pkg::a::b::c"), Some(LintLevel::Deny));
+    assert_eq!(level("pkg::a::bc"), None);
+    assert_eq!(level("pkg::a"), None);
+    assert_eq!(
+        config.lint_level("needless_return", file_path, "pkg::a::b"),
+        None
+    );
+}
+
+#[test]
+fn last_override_wins() {
+    let config = config_with_overrides(vec![
+        double_parens_override(&["src/**"], &[], LintLevel::Deny),
+        double_parens_override(&[], &["pkg::tests"], LintLevel::Allow),
+        double_parens_override(&["src/tests.cairo"], &[], LintLevel::Warn),
+    ]);
+    let level = |file_path: &str, module_path: &str| {
+        config.lint_level("double_parens", Path::new(file_path), module_path)
+    };
+    assert_eq!(
+        level("/package/src/lib.cairo", "pkg"),
+        Some(LintLevel::Deny)
+    );
+    assert_eq!(
+        level("/package/src/lib.cairo", "pkg::tests"),
+        Some(LintLevel::Allow)
+    );
+    assert_eq!(
+        level("/package/src/tests.cairo", "pkg::tests"),
+        Some(LintLevel::Warn)
+    );
+}
@@ -0,0 +1,83 @@
+use annotate_snippets::Renderer;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
+use cairo_lang_semantic::test_utils::setup_test_crate_ex;
+use cairo_lang_utils::Upcast;
+use cairo_lint_core::config::LintConfig;
+use cairo_lint_core::diagnostics::{format_diagnostic_with_fix, LintDetails};
+use cairo_lint_core::plugin::cairo_lint_plugin_suite_with_details;
+use cairo_lint_test_utils::get_diags;
+use pretty_assertions::assert_eq;
+
+const CRATE_CONFIG: &str = r#"
+edition = "2024_07"
+"#;
+
+/// Formats the diagnostics of the code like the CLI does when it's not run with `--fix`, i.e. with
+/// a `help:` footer showing the fixed code.
+fn format_with_fixes(code: &str) -> String {
+    let details = LintDetails::default();
+    let mut db = RootDatabase::builder()
+        .with_plugin_suite(get_default_plugin_suite())
+        .with_plugin_suite(cairo_lint_plugin_suite_with_details(
+            LintConfig::default(),
+            details.clone(),
+        ))
+        .build()
+        .unwrap();
+    let crate_id = setup_test_crate_ex(db.upcast(), code, Some(CRATE_CONFIG));
+    let renderer = Renderer::plain();
+    get_diags(crate_id, &mut db)
+        .into_iter()
+        .flat_map(|diags| diags.get_all())
+        .map(|diag| format_diagnostic_with_fix(&diag, &db, &renderer, &details))
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+#[test]
+fn help_footer_of_single_edit() {
+    let code = "fn main() {\n    let _x = ((10 * 2));\n}\n";
+    let expected = r#"warning: Plugin diagnostic: unnecessary double parentheses found. Consider removing them.
+ --> lib.cairo:2:14
+  |
+2 |     let _x = ((10 * 2));
+  |              ----------
+  |
+help: consider applying the suggested fix
+ --> lib.cairo:2:14
+  |
+2 |     let _x = 10 * 2;
+  |              ------
+  |"#;
+    assert_eq!(format_with_fixes(code), expected);
+}
+
+#[test]
+fn help_footer_of_multiple_edits() {
+    let code = "fn sum(values: Array<u32>) -> u32 {
+    let mut total = 0;
+    for value in values.span() {
+        total += *value;
+    }
+    total
+}
+";
+    let expected = r#"warning: Plugin diagnostic: This array parameter is only read. Consider taking a `Span` instead, so callers don't have to give up or clone their array.
+ --> lib.cairo:1:8
+  |
+1 |   fn sum(values: Array<u32>) -> u32 {
+  |          ------
+  |
+help: consider applying the suggested fix
+ --> lib.cairo:1:16
+  |
+1 |   fn sum(values: Span<u32>) -> u32 {
+  |  ________________-
+2 | |     let mut total = 0;
+3 | |     for value in values {
+  | |_______________________-
+  |"#;
+    assert_eq!(format_with_fixes(code), expected);
+}
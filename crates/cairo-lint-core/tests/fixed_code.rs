@@ -0,0 +1,104 @@
+use std::path::Path;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_diagnostics::{DiagnosticEntry, Severity};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
+use cairo_lang_semantic::test_utils::setup_test_crate_ex;
+use cairo_lang_test_utils::parse_test_file::parse_test_file;
+use cairo_lang_utils::Upcast;
+use cairo_lint_core::fix::{apply_fixes, collect_fixes};
+use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_test_utils::get_diags;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+const CRATE_CONFIG: &str = r#"
+edition = "2024_07"
+"#;
+
+/// Checks that the code fixed by the lints suggesting a combinator of the corelib compiles, i.e.
+/// that the combinators exist in the pinned corelib and that the closures have the right types.
+#[test_case("manual/manual_map"; "manual_map")]
+#[test_case("manual/manual_map_err"; "manual_map_err")]
+#[test_case("manual/manual_and_then"; "manual_and_then")]
+#[test_case("manual/manual_filter"; "manual_filter")]
+#[test_case("manual/manual_flatten"; "manual_flatten")]
+#[test_case("manual/manual_unwrap_or"; "manual_unwrap_or")]
+fn fixed_code_compiles(file_path: &str) {
+    let tests = parse_test_file(&Path::new("tests/test_files").join(file_path)).unwrap();
+    for (test_name, test) in tests {
+        if test_name.contains("nested") {
+            continue;
+        }
+        let fixed = test
+            .attributes
+            .get("fixed")
+            .expect("Couldn't get expected fix");
+        let mut db = RootDatabase::builder()
+            .with_plugin_suite(get_default_plugin_suite())
+            .build()
+            .unwrap();
+        let crate_id = setup_test_crate_ex(db.upcast(), fixed, Some(CRATE_CONFIG));
+        let errors = get_diags(crate_id, &mut db)
+            .into_iter()
+            .flat_map(|diags| diags.get_all())
+            .filter(|diag| diag.severity() == Severity::Error)
+            .map(|diag| diag.format(db.upcast()))
+            .collect::<Vec<_>>();
+        assert!(
+            errors.is_empty(),
+            "The fixed code of `{test_name}` doesn't compile:\n{}",
+            errors.join("\n")
+        );
+    }
+}
+
+/// Fixes the code like `scarb cairo-lint --fix` does.
+fn fix(code: &str) -> String {
+    let mut db = RootDatabase::builder()
+        .with_plugin_suite(get_default_plugin_suite())
+        .with_plugin_suite(cairo_lint_plugin_suite())
+        .build()
+        .unwrap();
+    let crate_id = setup_test_crate_ex(db.upcast(), code, Some(CRATE_CONFIG));
+    let diagnostics = get_diags(crate_id, &mut db)
+        .into_iter()
+        .flat_map(|diags| diags.get_all())
+        .collect::<Vec<_>>();
+    let mut fixes = collect_fixes(&db, &diagnostics).into_iter();
+    let (file_id, file_fixes) = fixes.next().expect("The code should be fixed");
+    assert!(fixes.next().is_none(), "The code is in a single file");
+    apply_fixes(&db.file_content(file_id).unwrap(), file_fixes)
+}
+
+#[test]
+fn multi_edit_fix_applied_with_all_its_edits() {
+    let code = "fn count(values: Array<u32>) -> u32 {
+    let len = ((values.len()));
+    len + values.span().len()
+}
+";
+    let expected = "fn count(values: Span<u32>) -> u32 {
+    let len = values.len();
+    len + values.len()
+}
+";
+    assert_eq!(fix(code), expected);
+}
+
+#[test]
+fn multi_edit_fix_overlapping_another_fix_skipped() {
+    let code = "fn count(values: Array<u32>) -> u32 {
+    let len = ((values.span())).len();
+    len + 1
+}
+";
+    // Changing the signature alone would leave a `.span()` call on a span.
+    let expected = "fn count(values: Array<u32>) -> u32 {
+    let len = values.span().len();
+    len + 1
+}
+";
+    assert_eq!(fix(code), expected);
+}
@@ -1,23 +1,19 @@
-use std::collections::HashMap;
 use std::path::Path;
 use std::sync::{LazyLock, Mutex};
 
 use annotate_snippets::Renderer;
 use cairo_lang_compiler::db::RootDatabase;
-use cairo_lang_filesystem::ids::FileId;
-use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
 use cairo_lang_semantic::inline_macros::get_default_plugin_suite;
 use cairo_lang_semantic::test_utils::setup_test_crate_ex;
-use cairo_lang_syntax::node::SyntaxNode;
+use cairo_lang_starknet::starknet_plugin_suite;
 use cairo_lang_test_plugin::test_plugin_suite;
 use cairo_lang_test_utils::parse_test_file::{dump_to_test_file, parse_test_file, Test};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::Upcast;
-use cairo_lint_core::diagnostics::format_diagnostic;
-use cairo_lint_core::fix::{
-    apply_import_fixes, collect_unused_imports, fix_semantic_diagnostic, Fix, ImportFix,
-};
-use cairo_lint_core::plugin::cairo_lint_plugin_suite;
+use cairo_lint_core::config::LintConfig;
+use cairo_lint_core::diagnostics::{format_diagnostic, LintDetails};
+use cairo_lint_core::fix::{apply_fixes, collect_fixes};
+use cairo_lint_core::plugin::cairo_lint_plugin_suite_with_details;
 use cairo_lint_test_utils::{get_diags, test_file, Tests};
 use ctor::dtor;
 use itertools::Itertools;
@@ -328,6 +324,70 @@ test_file!(
     "manual unwrap or default for match with comments"
 );
 
+test_file!(
+    manual,
+    manual_unwrap_or,
+    "manual unwrap or for match with integer",
+    "manual unwrap or for match with variable",
+    "manual unwrap or for if let with integer",
+    "manual unwrap or for if let with tuple",
+    "manual unwrap or for match with default not trigger",
+    "manual unwrap or else for match with function call",
+    "manual unwrap or else for if let with statements",
+    "manual unwrap or else for match with return not trigger",
+    "manual unwrap or else for match with panic is manual expect",
+    "manual unwrap or else allowed",
+    "manual unwrap or for match with literal arithmetic",
+    "manual unwrap or for match with comparison",
+    "manual unwrap or else for match with arithmetic on variables",
+    "manual unwrap or else with assigned outer variable not trigger",
+    "manual unwrap or else with outer variable passed by reference not trigger",
+    "manual unwrap or else with assigned inner variable"
+);
+
+test_file!(
+    manual,
+    manual_map,
+    "manual map for match",
+    "manual map for match on result",
+    "manual map for if let",
+    "manual map with same value not trigger",
+    "manual map with return not trigger",
+    "manual map with outer variable passed by reference not trigger",
+    "manual map with assigned outer variable not trigger"
+);
+
+test_file!(
+    manual,
+    manual_map_err,
+    "manual map err for match",
+    "manual map err with same error not trigger"
+);
+
+test_file!(
+    manual,
+    manual_and_then,
+    "manual and then for match",
+    "manual and then for match on result",
+    "manual and then for if let",
+    "manual and then with outer variable passed by reference not trigger"
+);
+
+test_file!(
+    manual,
+    manual_filter,
+    "manual filter for match",
+    "manual filter for if let",
+    "manual filter with method call"
+);
+
+test_file!(
+    manual,
+    manual_flatten,
+    "manual flatten for match",
+    "manual flatten for if let"
+);
+
 test_file!(
     manual,
     manual_expect,
@@ -435,7 +495,9 @@ test_file!(
     "if with functions",
     "Greater lesser comparison",
     "Same conditions with literals and vars",
-    "Same conditions with literals"
+    "Same conditions with literals",
+    "if let with different patterns",
+    "if let with same pattern"
 );
 
 test_file!(
@@ -451,3 +513,348 @@ test_file!(
     "int le min one",
     "int lt min one"
 );
+
+test_file!(
+    suppression,
+    expect,
+    "expect fulfilled",
+    "expect unfulfilled",
+    "expect unfulfilled because of an inner allow",
+    "expect in impl"
+);
+
+test_file!(
+    suppression,
+    unused_allow,
+    "used allow",
+    "unused allow",
+    "unused allow on statement",
+    "unused outer allow"
+);
+
+test_file!(
+    suppression,
+    comments,
+    "allow comment on statement",
+    "allow comment on function",
+    "allow comment for another lint",
+    "allow file comment",
+    "allow file comment not at the top",
+    "disable and enable comments",
+    "disable comment without enable"
+);
+
+test_file!(
+    array_init_then_append,
+    array_init_then_append,
+    "simple array init then append",
+    "array init with type then append",
+    "array init then append non literal values",
+    "array init then append and read",
+    "array init then append using the array",
+    "array init then append with comment",
+    "array init without append",
+    "array macro then append",
+    "array init then append allowed",
+    "array init then append with other fix in block"
+);
+
+test_file!(
+    felt252_arithmetic,
+    felt252_comparison,
+    "felt252 less than",
+    "felt252 greater than or equal",
+    "felt252 comparison with literal",
+    "felt252 equality",
+    "u32 comparison",
+    "felt252 comparison allowed"
+);
+
+test_file!(
+    felt252_arithmetic,
+    felt252_division,
+    "felt252 division",
+    "felt252 division by literal",
+    "u32 division",
+    "felt252 division allowed"
+);
+
+test_file!(
+    felt252_arithmetic,
+    felt252_sub_underflow,
+    "felt252 sub converted to unsigned integer",
+    "felt252 sub through variable converted to unsigned integer",
+    "felt252 sub compared",
+    "felt252 sub not converted",
+    "u32 sub converted to unsigned integer",
+    "felt252 sub converted to unsigned integer allowed"
+);
+
+test_file!(
+    div_before_mul,
+    div_before_mul,
+    "div before mul",
+    "div before mul on the right",
+    "div before mul through variable",
+    "div before mul through variable in another block",
+    "div before mul through mutable variable",
+    "mul before div",
+    "felt252 div before mul",
+    "div before mul allowed"
+);
+
+test_file!(
+    absurd_extreme_comparisons,
+    absurd_extreme_comparisons,
+    "unsigned less than zero",
+    "unsigned greater than or equal to zero",
+    "zero greater than unsigned",
+    "greater than bounded max",
+    "greater than or equal to bounded min",
+    "less than or equal to max literal",
+    "signed less than min literal",
+    "converted value compared with out of range literal",
+    "converted value equal to out of range literal",
+    "comparisons in range",
+    "absurd comparison allowed"
+);
+
+test_file!(
+    try_into_unwrap,
+    try_into_unwrap,
+    "felt252 try into unwrap",
+    "narrowing try into unwrap",
+    "widening try into unwrap",
+    "signed widening try into unwrap",
+    "integer to felt252 try into unwrap",
+    "widening try into unwrap in let",
+    "try into expect",
+    "try into unwrap allowed"
+);
+
+test_file!(
+    identity_op,
+    identity_op,
+    "addition of zero",
+    "zero addition",
+    "subtraction of zero",
+    "subtraction from zero",
+    "multiplication by one",
+    "division by one",
+    "one divided",
+    "bitwise or and xor with zero",
+    "identity operation on felt252",
+    "identity operation allowed",
+    "identity method calls",
+    "identity explicit call"
+);
+
+test_file!(
+    returns,
+    needless_return,
+    "return at the end of the function",
+    "return unit at the end of the function",
+    "return in tail if",
+    "return in tail match",
+    "early return",
+    "return in loop",
+    "return with comment",
+    "needless return allowed"
+);
+
+test_file!(
+    returns,
+    let_and_return,
+    "let and return",
+    "let and return in nested block",
+    "let and return after statements",
+    "let with type annotation and return",
+    "let and return other variable",
+    "let and return in array macro",
+    "let and return allowed",
+    "let and return with other fix in block"
+);
+
+test_file!(
+    bool_simplification,
+    needless_bool,
+    "if true else false",
+    "if false else true",
+    "if false else true on variable",
+    "if false else true on logical operator",
+    "if true else false in let",
+    "if with statements",
+    "if with same values",
+    "needless bool allowed"
+);
+
+test_file!(
+    bool_simplification,
+    nonminimal_bool,
+    "negated equality",
+    "negated inequality",
+    "negated ordering comparisons",
+    "double negation",
+    "negated logical operator",
+    "simple negation",
+    "nonminimal bool allowed"
+);
+
+test_file!(
+    starknet_security,
+    storage_write_after_external_call,
+    "storage write after dispatcher call",
+    "storage write before dispatcher call",
+    "map write after dispatcher call",
+    "storage write after call contract syscall",
+    "multiple storage writes after dispatcher call",
+    "external call and storage write in different functions",
+    "storage write after external call allowed",
+    "storage write and external call in different branches",
+    "storage write before external call in loop",
+    "storage write before external call in macro",
+    "storage write after external call in macro"
+);
+
+test_file!(
+    starknet_security,
+    unprotected_privileged_operation,
+    "ownership write without caller check",
+    "ownership write after caller check",
+    "ownership write after caller check with variable",
+    "ownership write before caller check",
+    "ownership write in constructor",
+    "ownership write in internal function",
+    "ownership write after guard function",
+    "replace class syscall in embedded impl",
+    "deploy syscall without caller check",
+    "unprotected privileged operation allowed",
+    "write to a field containing owner",
+    "ownership write after unasserted caller comparison",
+    "ownership write after caller check in branch",
+    "ownership write after unasserted caller check in if",
+    "ownership write in the branch of a failed caller check",
+    "ownership write in the else branch of a caller check",
+    "ownership write in the branch of a caller check",
+    "ownership write after caller check returning",
+    "ownership write after caller check panicking",
+    "ownership write after asserted caller inequality"
+);
+
+test_file!(
+    starknet_security,
+    unchecked_l1_handler_from_address,
+    "from address compared in assert",
+    "from address compared in if",
+    "unused from address",
+    "from address used without comparison",
+    "unchecked from address allowed"
+);
+
+test_file!(
+    performance,
+    storage_read_in_loop,
+    "storage read in for loop",
+    "storage read in while condition",
+    "storage read in loop",
+    "map read with key declared outside the loop",
+    "map read with key declared in the loop",
+    "map read with key modified in the loop",
+    "storage read and write in loop",
+    "storage read in nested loops",
+    "storage read in loop allowed",
+    "map entry read and write of another key in loop",
+    "map entry read and write of another field in loop",
+    "storage read in assert in loop",
+    "storage read in loop calling a helper writing the storage",
+    "storage read in loop calling a helper reading the storage",
+    "storage read in loop calling another contract"
+);
+
+test_file!(
+    starknet_security,
+    block_info_dependence,
+    "block timestamp modulo",
+    "block number modulo through variable",
+    "block timestamp hashed",
+    "block timestamp strict equality",
+    "block timestamp range comparison",
+    "block number from execution info strict equality",
+    "caller address from execution info",
+    "block info dependence allowed",
+    "block timestamp hash modulo",
+    "block timestamp comparison result compared"
+);
+
+test_file!(
+    starknet_security,
+    unemitted_event,
+    "event never emitted",
+    "all events emitted",
+    "events emitted through enum variants",
+    "event emitted in internal function",
+    "no event emitted",
+    "unemitted event allowed",
+    "component event not emitted by the contract",
+    "event enum of another module never emitted"
+);
+
+test_file!(
+    starknet_security,
+    unused_storage_field,
+    "storage field never used",
+    "storage field written but never read",
+    "storage field read",
+    "map field written through entry",
+    "vec field length read",
+    "unused storage field allowed",
+    "storage field read in nested module",
+    "storage field read in helper function taking contract state"
+);
+
+test_file!(
+    starknet_security,
+    missing_zero_address_check,
+    "address written without zero check",
+    "address checked with is_non_zero",
+    "address compared with zero",
+    "address compared with zero address constant",
+    "address checked after the write",
+    "felt252 parameter written",
+    "missing zero address check allowed",
+    "address checked before calling internal setter",
+    "address checked in internal setter",
+    "address passed unchecked to internal setter",
+    "address written in internal function only"
+);
+
+test_file!(
+    array_param_only_read,
+    array_param_only_read,
+    "array param only read with len and at",
+    "array param iterated through span",
+    "array param indexed",
+    "array param read with get",
+    "array param moved",
+    "array param iterated by value",
+    "array param in trait impl",
+    "array param in generate trait impl",
+    "array param only read allowed",
+    "array param in contract entry points",
+    "array param only read with other fix in function",
+    "array param iterated by value with non copyable items",
+    "array param shadowed by local array"
+);
+
+test_file!(
+    redundant_clone,
+    redundant_clone,
+    "clone of local variable not used afterwards",
+    "clone of variable used afterwards",
+    "clone of parameter not used afterwards",
+    "clone of ref parameter",
+    "clone in loop of variable declared outside",
+    "clone in loop of variable declared in loop",
+    "clone of variable passed by reference afterwards",
+    "redundant clone allowed"
+);
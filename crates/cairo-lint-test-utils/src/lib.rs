@@ -60,46 +60,33 @@ macro_rules! test_file {
                 let test =  [<PARSED_TEST_FILE_ $file_path:upper>].get(test_name).expect("Couldn't get test");
                 let is_fix_mode = std::env::var("FIX_TESTS") == Ok("1".into());
                 let mut file = test.attributes.get("cairo_code").expect("Couldn't get cairo code").clone();
+                let details = LintDetails::default();
                 let mut db = RootDatabase::builder()
                     .with_plugin_suite(get_default_plugin_suite())
                     .with_plugin_suite(test_plugin_suite())
-                    .with_plugin_suite(cairo_lint_plugin_suite())
+                    .with_plugin_suite(starknet_plugin_suite())
+                    // The unused `allow` attributes are only reported in the suppression fixtures, as
+                    // in the CLI they're only reported when opted in.
+                    .with_plugin_suite(cairo_lint_plugin_suite_with_details(LintConfig {
+                        unused_allow: stringify!($lint_group) == "suppression",
+                        ..Default::default()
+                    }, details.clone()))
                     .build()
                     .unwrap();
 
                 let diags = get_diags(setup_test_crate_ex(db.upcast(), &file, Some(CRATE_CONFIG)), &mut db);
                 // Transform Vec<Diagnostics<Semantic>> into Vec<Semantic>
                 let semantic_diags: Vec<_> = diags.clone().into_iter().flat_map(|diag| diag.get_all()).collect();
-                let unused_imports: HashMap<FileId, HashMap<SyntaxNode, ImportFix>> =
-                    collect_unused_imports(&db, &semantic_diags);
-                let mut fixes = if unused_imports.keys().len() > 0 {
-                    let current_file_id = unused_imports.keys().next().unwrap();
-                    apply_import_fixes(&db, unused_imports.get(&current_file_id).unwrap())
-                } else {
-                    Vec::new()
-                };
-
-                // Handle other types of fixes
-                for diag in diags.iter().flat_map(|diags| diags.get_all()) {
-                    if !matches!(diag.kind, SemanticDiagnosticKind::UnusedImport(_)) {
-                        if let Some((fix_node, fix)) = fix_semantic_diagnostic(&db, &diag) {
-                            let span = fix_node.span(db.upcast());
-                            fixes.push(Fix { span, suggestion: fix });
-                        }
-                    }
-                }
-
-                fixes.sort_by_key(|v| std::cmp::Reverse(v.span.start));
                 if !test_name.contains("nested") {
-                    for fix in fixes.iter() {
-                        file.replace_range(fix.span.to_str_range(), &fix.suggestion);
-                    }
+                    // The fixes are applied like the CLI does, all the code being in a single file.
+                    let fixes = collect_fixes(&db, &semantic_diags).into_values().flatten().collect();
+                    file = apply_fixes(&file, fixes);
                 } else {
                     file = "Contains nested diagnostics can't fix it".to_string();
                 }
                 let renderer = Renderer::plain();
                 let formatted_diags =
-                    diags.into_iter().flat_map(|diags| diags.get_all().iter().map(|diag| format_diagnostic(diag, &db, &renderer)).collect::<Vec<_>>()).collect::<String>().trim().to_string();
+                    diags.into_iter().flat_map(|diags| diags.get_all().iter().map(|diag| format_diagnostic(diag, &db, &renderer, &details)).collect::<Vec<_>>()).collect::<String>().trim().to_string();
                 if is_fix_mode {
                     let mut new_test = test.clone();
                     new_test.attributes.insert("diagnostics".to_string(), formatted_diags.clone());